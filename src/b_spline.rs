@@ -0,0 +1,387 @@
+use crate::{
+    CubicCurve,
+    InnerProductSpace,
+    QuadraticCurve,
+    Scalar,
+    Spline,
+};
+
+// The highest supported degree, so that evaluation can work in a buffer on the stack
+const MAX_DEGREE: usize = 15;
+
+// There are always degree + 1 more knots than control points, and the curve is defined on [knots[degree], knots[points]]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BSpline<T: InnerProductSpace> {
+    degree: usize,
+    knots: Vec<T::Scalar>,
+    control_points: Vec<T>,
+}
+
+impl<T: InnerProductSpace> BSpline<T> {
+    pub fn new(degree: usize, knots: Vec<T::Scalar>, control_points: Vec<T>) -> BSpline<T> {
+        assert!(degree <= MAX_DEGREE, "splines of degree greater than {} are not supported", MAX_DEGREE);
+        assert!(control_points.len() > degree, "there must be more control points than the degree");
+        assert_eq!(knots.len(), control_points.len() + degree + 1, "there must be degree + 1 more knots than control points");
+        assert!(knots.windows(2).all(|pair| pair[0] <= pair[1]), "knots must be nondecreasing");
+        assert!(knots[degree] < knots[control_points.len()], "the domain must not be empty");
+
+        BSpline {
+            degree,
+            knots,
+            control_points,
+        }
+    }
+
+    // Knots at 0, 1, 2, ...
+    pub fn uniform(degree: usize, control_points: Vec<T>) -> BSpline<T> {
+        let knots = (0..(control_points.len() + degree + 1) as i32).map(T::Scalar::from_i32).collect();
+        BSpline::new(degree, knots, control_points)
+    }
+
+    // Uniform knots with the ends repeated so the curve starts and ends at the first and last control points
+    pub fn clamped(degree: usize, control_points: Vec<T>) -> BSpline<T> {
+        assert!(control_points.len() > degree, "there must be more control points than the degree");
+
+        let last = (control_points.len() - degree) as i32;
+        let knots = (0..(control_points.len() + degree + 1) as i32)
+            .map(|i| T::Scalar::from_i32((i - degree as i32).clamp(0, last)))
+            .collect();
+        BSpline::new(degree, knots, control_points)
+    }
+
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    pub fn knots(&self) -> &[T::Scalar] {
+        &self.knots
+    }
+
+    pub fn control_points(&self) -> &[T] {
+        &self.control_points
+    }
+
+    pub fn domain(&self) -> (T::Scalar, T::Scalar) {
+        (self.knots[self.degree], self.knots[self.control_points.len()])
+    }
+
+    // The index k of the nonempty knot span [knots[k], knots[k + 1]) containing t, using the last span for the end of the domain
+    fn span(&self, t: T::Scalar) -> usize {
+        let mut k = self.degree;
+        for i in self.degree..self.control_points.len() {
+            if self.knots[i] <= t && self.knots[i] < self.knots[i + 1] {
+                k = i;
+            }
+        }
+        k
+    }
+
+    fn clamp(&self, t: T::Scalar) -> T::Scalar {
+        let (start, end) = self.domain();
+        if t < start {
+            start
+        } else if t > end {
+            end
+        } else {
+            t
+        }
+    }
+
+    // De Boor's algorithm on span k for the first degree + 1 points, where level r uses argument(r - 1). The derivative
+    // spline has the same knots without the first, so its spans read the knots with the same indices for one less degree
+    fn de_boor<F: Fn(usize) -> T::Scalar>(&self, k: usize, degree: usize, points: &mut [T], argument: F) -> T {
+        for r in 1..=degree {
+            let x = argument(r - 1);
+            for j in (r..=degree).rev() {
+                let lo = self.knots[j + k - degree];
+                let hi = self.knots[j + 1 + k - r];
+                let alpha = (x - lo) / (hi - lo);
+                points[j] = points[j - 1] * (T::Scalar::from_i32(1) - alpha) + points[j] * alpha;
+            }
+        }
+        points[degree]
+    }
+
+    // The blossom of span k, where level r of de Boor's algorithm uses argument(r - 1)
+    fn blossom<F: Fn(usize) -> T::Scalar>(&self, k: usize, argument: F) -> T {
+        let p = self.degree;
+        let mut points = [T::zero(); MAX_DEGREE + 1];
+        points[..=p].copy_from_slice(&self.control_points[k - p..=k]);
+        self.de_boor(k, p, &mut points, argument)
+    }
+
+    // Clamps to the domain
+    pub fn evaluate(&self, t: T::Scalar) -> T {
+        let t = self.clamp(t);
+        self.blossom(self.span(t), |_| t)
+    }
+
+    // Control point i of the derivative spline
+    fn derivative_point(&self, i: usize) -> T {
+        let p = self.degree;
+        let width = self.knots[i + p + 1] - self.knots[i + 1];
+        if width == T::Scalar::from_i32(0) {
+            T::zero()
+        } else {
+            (self.control_points[i + 1] - self.control_points[i]) * (T::Scalar::from_i32(p as i32) / width)
+        }
+    }
+
+    pub fn derivative(&self) -> BSpline<T> {
+        assert!(self.degree > 0, "a spline of degree zero has no derivative spline");
+
+        let control_points = (0..self.control_points.len() - 1).map(|i| self.derivative_point(i)).collect();
+
+        BSpline {
+            degree: self.degree - 1,
+            knots: self.knots[1..self.knots.len() - 1].to_vec(),
+            control_points,
+        }
+    }
+
+    // Clamps to the domain
+    pub fn evaluate_derivative(&self, t: T::Scalar) -> T {
+        if self.degree == 0 {
+            return T::zero();
+        }
+
+        // the span from knots[k] to knots[k + 1] of the derivative spline uses its control points k - p to k - 1
+        let t = self.clamp(t);
+        let k = self.span(t);
+        let p = self.degree;
+        let mut points = [T::zero(); MAX_DEGREE + 1];
+        for (j, point) in points[..p].iter_mut().enumerate() {
+            *point = self.derivative_point(k - p + j);
+        }
+        self.de_boor(k, p - 1, &mut points, |_| t)
+    }
+
+    // Boehm's algorithm, which adds a knot without changing the shape of the curve
+    pub fn insert_knot(&mut self, t: T::Scalar) {
+        let (start, end) = self.domain();
+        assert!(t >= start && t <= end, "knots can only be inserted inside the domain");
+
+        let p = self.degree;
+        let k = self.span(t);
+        let mut control_points = Vec::with_capacity(self.control_points.len() + 1);
+        control_points.extend_from_slice(&self.control_points[..=k - p]);
+        for i in k - p + 1..=k {
+            let alpha = (t - self.knots[i]) / (self.knots[i + p] - self.knots[i]);
+            control_points.push(self.control_points[i - 1] * (T::Scalar::from_i32(1) - alpha) + self.control_points[i] * alpha);
+        }
+        control_points.extend_from_slice(&self.control_points[k..]);
+
+        self.control_points = control_points;
+        self.knots.insert(k + 1, t);
+    }
+
+    // The Bezier control points of each nonempty knot span in the domain
+    pub fn to_bezier(&self) -> Vec<Vec<T>> {
+        let p = self.degree;
+        (p..self.control_points.len())
+            .filter(|&k| self.knots[k] < self.knots[k + 1])
+            .map(|k| {
+                let (lo, hi) = (self.knots[k], self.knots[k + 1]);
+                (0..=p)
+                    .map(|j| self.blossom(k, |r| if r < j { hi } else { lo }))
+                    .collect()
+            })
+            .collect()
+    }
+
+    // The ends of the nonempty knot spans in the domain, which are the knots of the splines of to_bezier
+    fn span_knots(&self) -> Vec<T::Scalar> {
+        let mut knots = vec![self.knots[self.degree]];
+        knots.extend(
+            (self.degree..self.control_points.len())
+                .filter(|&k| self.knots[k] < self.knots[k + 1])
+                .map(|k| self.knots[k + 1]),
+        );
+        knots
+    }
+
+    // Each nonempty knot span becomes one segment
+    pub fn to_quadratic_spline(&self) -> Spline<QuadraticCurve<T>> {
+        assert!(self.degree <= 2, "only splines of degree two or less can be converted to quadratic curves");
+
+        let segments = self.to_bezier()
+            .into_iter()
+            .map(|points| {
+                let points = elevate(points, 3);
+                QuadraticCurve::from_bezier(points[0], points[1], points[2])
+            })
+            .collect();
+        Spline::with_knots(segments, self.span_knots())
+    }
+
+    // Each nonempty knot span becomes one segment
+    pub fn to_cubic_spline(&self) -> Spline<CubicCurve<T>> {
+        assert!(self.degree <= 3, "only splines of degree three or less can be converted to cubic curves");
+
+        let segments = self.to_bezier()
+            .into_iter()
+            .map(|points| {
+                let points = elevate(points, 4);
+                CubicCurve::from_bezier(points[0], points[1], points[2], points[3])
+            })
+            .collect();
+        Spline::with_knots(segments, self.span_knots())
+    }
+}
+
+// Raises the degree of Bezier control points until there are count of them
+pub fn elevate<T: InnerProductSpace>(mut points: Vec<T>, count: usize) -> Vec<T> {
+    while points.len() < count {
+        let n = points.len() as i32;
+        let mut elevated = Vec::with_capacity(points.len() + 1);
+        elevated.push(points[0]);
+        for i in 1..n {
+            let alpha = T::Scalar::from_i32(i) / T::Scalar::from_i32(n);
+            elevated.push(points[i as usize - 1] * alpha + points[i as usize] * (T::Scalar::from_i32(1) - alpha));
+        }
+        elevated.push(points[n as usize - 1]);
+        points = elevated;
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    mod b_spline {
+        use crate::{
+            BSpline,
+            CubicCurve,
+            Vector2,
+        };
+        use approx::{
+            assert_abs_diff_eq,
+            assert_ulps_eq,
+        };
+
+        fn points() -> Vec<Vector2<f32>> {
+            vec![
+                Vector2::new(0f32, 0f32),
+                Vector2::new(1f32, 2f32),
+                Vector2::new(2f32, -1f32),
+                Vector2::new(4f32, 3f32),
+                Vector2::new(5f32, 0f32),
+                Vector2::new(7f32, 1f32),
+            ]
+        }
+
+        fn non_uniform() -> BSpline<Vector2<f32>> {
+            BSpline::new(3, vec![0f32, 0f32, 0f32, 0f32, 0.5f32, 2f32, 3f32, 3f32, 3f32, 3f32], points())
+        }
+
+        fn assert_close(a: Vector2<f32>, b: Vector2<f32>) {
+            assert_abs_diff_eq!(a.x, b.x, epsilon = 0.0001f32);
+            assert_abs_diff_eq!(a.y, b.y, epsilon = 0.0001f32);
+        }
+
+        #[test]
+        fn evaluate() {
+            // a clamped spline with one span is a Bezier curve
+            let points = points();
+            let spline = BSpline::clamped(3, points[..4].to_vec());
+            let bezier = CubicCurve::from_bezier(points[0], points[1], points[2], points[3]);
+            for i in 0..=10 {
+                let t = i as f32 / 10f32;
+                assert_close(spline.evaluate(t), bezier.evaluate(t));
+            }
+
+            // uniform cubic splines pass through (p0 + 4 p1 + p2) / 6 at each knot
+            let spline = BSpline::uniform(3, points.clone());
+            assert_eq!(spline.domain(), (3f32, 6f32));
+            for i in 0..4 {
+                let expected = (points[i] + points[i + 1] * 4f32 + points[i + 2]) / 6f32;
+                assert_close(spline.evaluate(3f32 + i as f32), expected);
+            }
+            assert_close(spline.evaluate(-1f32), spline.evaluate(3f32));
+            assert_close(spline.evaluate(10f32), spline.evaluate(6f32));
+
+            let spline = non_uniform();
+            assert_eq!(spline.evaluate(0f32), points[0]);
+            assert_close(spline.evaluate(3f32), points[5]);
+        }
+
+        #[test]
+        fn insert_knot() {
+            for spline in &[non_uniform(), BSpline::uniform(2, points())] {
+                let mut inserted = spline.clone();
+                let (start, end) = spline.domain();
+                for &t in &[start, start + 0.3f32, (start + end) / 2f32, end - 0.01f32, end] {
+                    inserted.insert_knot(t);
+                }
+                assert_eq!(inserted.control_points().len(), spline.control_points().len() + 5);
+                assert_eq!(inserted.knots().len(), spline.knots().len() + 5);
+                for i in 0..=20 {
+                    let t = start + (end - start) * i as f32 / 20f32;
+                    assert_close(inserted.evaluate(t), spline.evaluate(t));
+                }
+            }
+        }
+
+        #[test]
+        fn derivative() {
+            for spline in &[non_uniform(), BSpline::uniform(3, points()), BSpline::clamped(2, points())] {
+                let derivative = spline.derivative();
+                assert_eq!(derivative.degree(), spline.degree() - 1);
+                let (start, end) = spline.domain();
+                assert_eq!(derivative.domain(), (start, end));
+                for i in 1..20 {
+                    let t = start + (end - start) * i as f32 / 20f32;
+                    let h = 0.001f32;
+                    let difference = (spline.evaluate(t + h) - spline.evaluate(t - h)) / (2f32 * h);
+                    assert_abs_diff_eq!(derivative.evaluate(t).x, difference.x, epsilon = 0.01f32);
+                    assert_abs_diff_eq!(derivative.evaluate(t).y, difference.y, epsilon = 0.01f32);
+                    assert_eq!(spline.evaluate_derivative(t), derivative.evaluate(t));
+                }
+            }
+        }
+
+        #[test]
+        fn bezier_extraction() {
+            let spline = non_uniform();
+            let cubics = spline.to_cubic_spline();
+            let spans = [(0f32, 0.5f32), (0.5f32, 2f32), (2f32, 3f32)];
+            assert_eq!(cubics.len(), 3);
+            assert_eq!(cubics.knots(), &[0f32, 0.5f32, 2f32, 3f32]);
+            for (i, &(lo, hi)) in spans.iter().enumerate() {
+                for j in 0..=10 {
+                    let u = j as f32 / 10f32;
+                    assert_close(cubics.segments()[i].evaluate(u), spline.evaluate(lo + (hi - lo) * u));
+                    assert_close(cubics.evaluate(lo + (hi - lo) * u).unwrap(), spline.evaluate(lo + (hi - lo) * u));
+                }
+            }
+
+            let spline = BSpline::uniform(2, points());
+            let quadratics = spline.to_quadratic_spline();
+            let cubics = spline.to_cubic_spline();
+            assert_eq!(quadratics.len(), 4);
+            assert_eq!(quadratics.knots(), &[2f32, 3f32, 4f32, 5f32, 6f32]);
+            for i in 20..=60 {
+                let t = i as f32 / 10f32;
+                assert_close(quadratics.evaluate(t).unwrap(), spline.evaluate(t));
+                assert_close(cubics.evaluate(t).unwrap(), spline.evaluate(t));
+            }
+
+            // the segments keep the spacing of the knots
+            let spline = BSpline::new(2, vec![0f32, 0f32, 0f32, 1f32, 3f32, 3f32, 3f32], points()[..4].to_vec());
+            let quadratics = spline.to_quadratic_spline();
+            let cubics = spline.to_cubic_spline();
+            assert_eq!(quadratics.knots(), &[0f32, 1f32, 3f32]);
+            for i in 0..=30 {
+                let t = i as f32 / 10f32;
+                assert_close(quadratics.evaluate(t).unwrap(), spline.evaluate(t));
+                assert_close(cubics.evaluate(t).unwrap(), spline.evaluate(t));
+            }
+
+            // linear splines are their control polygon
+            let spline = BSpline::clamped(1, points());
+            let cubics = spline.to_cubic_spline();
+            assert_eq!(cubics.len(), 5);
+            assert_ulps_eq!(cubics.evaluate(2.5f32).unwrap().x, 3f32);
+            assert_ulps_eq!(cubics.evaluate(2.5f32).unwrap().y, 1f32);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,288 @@
+use crate::{
+    CubicCurve,
+    InnerProductSpace,
+    LinearCurve,
+    PolynomialCurve,
+    QuadraticCurve,
+    Scalar,
+};
+
+// The curve has N control points, and so is of degree N - 1
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BezierCurve<T: InnerProductSpace, const N: usize> {
+    points: [T; N],
+}
+
+impl<T: InnerProductSpace, const N: usize> BezierCurve<T, N> {
+    pub fn new(points: [T; N]) -> BezierCurve<T, N> {
+        assert!(N > 0, "a curve needs at least one control point");
+
+        BezierCurve {
+            points,
+        }
+    }
+
+    pub fn points(&self) -> [T; N] {
+        self.points
+    }
+
+    pub fn degree(&self) -> usize {
+        N - 1
+    }
+
+    pub fn evaluate(&self, t: T::Scalar) -> T {
+        let w = T::Scalar::from_i32(1) - t;
+        let mut points = self.points;
+        for n in (1..N).rev() {
+            for i in 0..n {
+                points[i] = points[i] * w + points[i + 1] * t;
+            }
+        }
+        points[0]
+    }
+
+    pub fn evaluate_derivative(&self, t: T::Scalar) -> T {
+        if N < 2 {
+            T::zero()
+        } else {
+            // the derivative is a bezier curve of one degree less whose control points are the scaled differences
+            let w = T::Scalar::from_i32(1) - t;
+            let mut points = [T::zero(); N];
+            for (point, pair) in points.iter_mut().zip(self.points.windows(2)) {
+                *point = pair[1] - pair[0];
+            }
+            for n in (1..N - 1).rev() {
+                for i in 0..n {
+                    points[i] = points[i] * w + points[i + 1] * t;
+                }
+            }
+            points[0] * T::Scalar::from_i32(N as i32 - 1)
+        }
+    }
+
+    pub fn subdivide(&self, u: T::Scalar) -> (BezierCurve<T, N>, BezierCurve<T, N>) {
+        let w = T::Scalar::from_i32(1) - u;
+        let mut left = [T::zero(); N];
+        let mut right = [T::zero(); N];
+        let mut points = self.points;
+        for n in (0..N).rev() {
+            // each level of the de Casteljau triangle contributes one point to either side
+            left[N - 1 - n] = points[0];
+            right[n] = points[n];
+            for i in 0..n {
+                points[i] = points[i] * w + points[i + 1] * u;
+            }
+        }
+
+        (
+            BezierCurve::new(left),
+            BezierCurve::new(right),
+        )
+    }
+
+    // The curve lies inside the convex hull of its control points, and so inside any sphere that contains them
+    pub fn bounding_sphere(&self) -> (T, T::Scalar) {
+        let mut center = T::zero();
+        for &point in self.points.iter() {
+            center += point;
+        }
+        center /= T::Scalar::from_i32(N as i32);
+
+        let mut radius = T::Scalar::from_i32(0);
+        for &point in self.points.iter() {
+            let distance = (point - center).inner_product(point - center).sqrt();
+            if distance > radius {
+                radius = distance;
+            }
+        }
+
+        (center, radius)
+    }
+
+    // The curve lies inside the convex hull of its control points, so no point on the curve is further from the chord
+    // between its endpoints than the furthest control point
+    pub fn chord_distance_bound(&self) -> T::Scalar {
+        if N <= 2 {
+            // the curve is its chord
+            return T::Scalar::from_i32(0);
+        }
+
+        let start = self.points[0];
+        let chord = self.points[N - 1] - start;
+        let chord_length_squared = chord.inner_product(chord);
+
+        let mut bound = T::Scalar::from_i32(0);
+        for &point in self.points[1..N - 1].iter() {
+            let offset = point - start;
+            let mut t = if chord_length_squared == T::Scalar::from_i32(0) {
+                T::Scalar::from_i32(0)
+            } else {
+                offset.inner_product(chord) / chord_length_squared
+            };
+            if t < T::Scalar::from_i32(0) {
+                t = T::Scalar::from_i32(0);
+            } else if t > T::Scalar::from_i32(1) {
+                t = T::Scalar::from_i32(1);
+            }
+            let difference = offset - chord * t;
+            let distance = difference.inner_product(difference).sqrt();
+            if distance > bound {
+                bound = distance;
+            }
+        }
+        bound
+    }
+}
+
+impl<T: InnerProductSpace, const N: usize> From<PolynomialCurve<T, N>> for BezierCurve<T, N> {
+    fn from(curve: PolynomialCurve<T, N>) -> Self {
+        BezierCurve::new(curve.to_bezier())
+    }
+}
+
+impl<T: InnerProductSpace, const N: usize> From<BezierCurve<T, N>> for PolynomialCurve<T, N> {
+    fn from(curve: BezierCurve<T, N>) -> Self {
+        PolynomialCurve::from_bezier(curve.points)
+    }
+}
+
+impl<T: InnerProductSpace> From<LinearCurve<T>> for BezierCurve<T, 2> {
+    fn from(curve: LinearCurve<T>) -> Self {
+        let (b0, b1) = curve.to_bezier();
+        BezierCurve::new([b0, b1])
+    }
+}
+
+impl<T: InnerProductSpace> From<QuadraticCurve<T>> for BezierCurve<T, 3> {
+    fn from(curve: QuadraticCurve<T>) -> Self {
+        let (b0, b1, b2) = curve.to_bezier();
+        BezierCurve::new([b0, b1, b2])
+    }
+}
+
+impl<T: InnerProductSpace> From<CubicCurve<T>> for BezierCurve<T, 4> {
+    fn from(curve: CubicCurve<T>) -> Self {
+        let (b0, b1, b2, b3) = curve.to_bezier();
+        BezierCurve::new([b0, b1, b2, b3])
+    }
+}
+
+impl<T: InnerProductSpace> From<BezierCurve<T, 2>> for LinearCurve<T> {
+    fn from(curve: BezierCurve<T, 2>) -> Self {
+        let [b0, b1] = curve.points;
+        LinearCurve::from_bezier(b0, b1)
+    }
+}
+
+impl<T: InnerProductSpace> From<BezierCurve<T, 3>> for QuadraticCurve<T> {
+    fn from(curve: BezierCurve<T, 3>) -> Self {
+        let [b0, b1, b2] = curve.points;
+        QuadraticCurve::from_bezier(b0, b1, b2)
+    }
+}
+
+impl<T: InnerProductSpace> From<BezierCurve<T, 4>> for CubicCurve<T> {
+    fn from(curve: BezierCurve<T, 4>) -> Self {
+        let [b0, b1, b2, b3] = curve.points;
+        CubicCurve::from_bezier(b0, b1, b2, b3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod bezier_curve {
+        use crate::{
+            BezierCurve,
+            CubicCurve,
+            InnerProductSpace,
+            QuadraticCurve,
+            Vector2,
+        };
+        use approx::{
+            assert_abs_diff_eq,
+            assert_ulps_eq,
+        };
+
+        #[test]
+        fn evaluate() {
+            let curve = BezierCurve::new([1f32, -1f32, 0.5f32, 4f32]);
+            let cubic = CubicCurve::from(curve);
+            assert_eq!(curve.degree(), 3);
+            for i in 0..=10 {
+                let t = i as f32 / 10f32;
+                assert_abs_diff_eq!(curve.evaluate(t), cubic.evaluate(t), epsilon = 0.00001f32);
+                assert_abs_diff_eq!(curve.evaluate_derivative(t), cubic.evaluate_first_derivative(t), epsilon = 0.0001f32);
+            }
+
+            // the endpoints are exact, even far from the origin
+            let curve = BezierCurve::new([100000.1f32, 100000.2f32, 100000.3f32]);
+            assert_eq!(curve.evaluate(0f32), 100000.1f32);
+            assert_eq!(curve.evaluate(1f32), 100000.3f32);
+            assert_eq!(curve.evaluate(0.5f32), 100000.2f32);
+        }
+
+        #[test]
+        fn subdivide() {
+            let cubic = CubicCurve::new(1f32, 1f32, 1f32, 1f32);
+            let (cubic_left, cubic_right) = cubic.subdivide(0.25f32);
+            let (left, right) = BezierCurve::from(cubic).subdivide(0.25f32);
+            let left = CubicCurve::from(left);
+            let right = CubicCurve::from(right);
+            assert_ulps_eq!(left.x0(), cubic_left.x0());
+            assert_ulps_eq!(left.x1(), cubic_left.x1());
+            assert_ulps_eq!(left.x2(), cubic_left.x2());
+            assert_ulps_eq!(left.x3(), cubic_left.x3());
+            assert_ulps_eq!(right.x0(), cubic_right.x0());
+            assert_ulps_eq!(right.x1(), cubic_right.x1());
+            assert_ulps_eq!(right.x2(), cubic_right.x2());
+            assert_ulps_eq!(right.x3(), cubic_right.x3());
+
+            let curve = BezierCurve::new([Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32), Vector2::new(3f32, 3f32), Vector2::new(4f32, 0f32), Vector2::new(5f32, 1f32)]);
+            let (left, right) = curve.subdivide(0.5f32);
+            assert_eq!(left.points()[0], curve.points()[0]);
+            assert_eq!(left.points()[4], right.points()[0]);
+            assert_eq!(right.points()[4], curve.points()[4]);
+            for i in 0..=10 {
+                let t = i as f32 / 10f32;
+                let expected = curve.evaluate(0.5f32 * t);
+                assert_abs_diff_eq!(left.evaluate(t).x, expected.x, epsilon = 0.00001f32);
+                assert_abs_diff_eq!(left.evaluate(t).y, expected.y, epsilon = 0.00001f32);
+                let expected = curve.evaluate(0.5f32 + 0.5f32 * t);
+                assert_abs_diff_eq!(right.evaluate(t).x, expected.x, epsilon = 0.00001f32);
+                assert_abs_diff_eq!(right.evaluate(t).y, expected.y, epsilon = 0.00001f32);
+            }
+        }
+
+        #[test]
+        fn convert() {
+            let quadratic = QuadraticCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(0.5f32, 1f32), Vector2::new(1f32, 0f32));
+            let curve = BezierCurve::from(quadratic);
+            assert_eq!(curve.points(), [Vector2::new(0f32, 0f32), Vector2::new(0.5f32, 1f32), Vector2::new(1f32, 0f32)]);
+            assert_eq!(QuadraticCurve::from(curve), quadratic);
+
+            let cubic = CubicCurve::from_bezier(1f32, 2f32, 4f32, 8f32);
+            assert_eq!(CubicCurve::from(BezierCurve::from(cubic)), cubic);
+        }
+
+        #[test]
+        fn convex_hull() {
+            let curve = BezierCurve::new([Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32), Vector2::new(3f32, -1f32), Vector2::new(4f32, 0f32)]);
+            let (center, radius) = curve.bounding_sphere();
+            let bound = curve.chord_distance_bound();
+            assert_ulps_eq!(bound, 2f32);
+            for i in 0..=100 {
+                let point = curve.evaluate(i as f32 / 100f32);
+                assert!((point - center).inner_product(point - center).sqrt() <= radius);
+                assert!(point.y.abs() <= bound);
+            }
+
+            let curve = BezierCurve::new([1f32, 1f32, 1f32]);
+            assert_ulps_eq!(curve.bounding_sphere().1, 0f32);
+            assert_ulps_eq!(curve.chord_distance_bound(), 0f32);
+
+            // lines and points have no control points between their ends
+            assert_ulps_eq!(BezierCurve::new([Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32)]).chord_distance_bound(), 0f32);
+            assert_ulps_eq!(BezierCurve::new([Vector2::new(1f32, 2f32)]).chord_distance_bound(), 0f32);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,357 @@
+use crate::{
+    CubicCurve,
+    InnerProductSpace,
+    QuadraticCurve,
+    Scalar,
+    Vector2,
+    Vector3,
+    VectorSpace,
+    roots::{
+        Roots,
+        solve_linear_in,
+        solve_quadratic_in,
+    },
+};
+
+// A box whose edges are parallel to the coordinate axes
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoundingBox<T: InnerProductSpace> {
+    min: T,
+    max: T,
+}
+
+impl<T: InnerProductSpace> BoundingBox<T> {
+    pub fn new(min: T, max: T) -> BoundingBox<T> {
+        BoundingBox {
+            min,
+            max,
+        }
+    }
+
+    pub fn min(&self) -> T {
+        self.min
+    }
+
+    pub fn max(&self) -> T {
+        self.max
+    }
+}
+
+// A box whose edges are parallel to N orthonormal axes, which contains the points whose inner products with each axis
+// are between the corresponding min and max
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OrientedBoundingBox<T: InnerProductSpace, const N: usize> {
+    axes: [T; N],
+    min: [T::Scalar; N],
+    max: [T::Scalar; N],
+}
+
+impl<T: InnerProductSpace, const N: usize> OrientedBoundingBox<T, N> {
+    pub fn new(axes: [T; N], min: [T::Scalar; N], max: [T::Scalar; N]) -> OrientedBoundingBox<T, N> {
+        OrientedBoundingBox {
+            axes,
+            min,
+            max,
+        }
+    }
+
+    pub fn axes(&self) -> [T; N] {
+        self.axes
+    }
+
+    pub fn min(&self) -> [T::Scalar; N] {
+        self.min
+    }
+
+    pub fn max(&self) -> [T::Scalar; N] {
+        self.max
+    }
+
+    pub fn contains(&self, point: T) -> bool {
+        (0..N).all(|i| {
+            let value = point.inner_product(self.axes[i]);
+            self.min[i] <= value && value <= self.max[i]
+        })
+    }
+
+    fn from_extents<F: Fn(T) -> (T::Scalar, T::Scalar)>(axes: [T; N], extent: F) -> OrientedBoundingBox<T, N> {
+        let extents = axes.map(extent);
+        OrientedBoundingBox::new(axes, extents.map(|(min, _)| min), extents.map(|(_, max)| max))
+    }
+}
+
+// The smallest and largest values of the polynomial over [0, 1], given its values at the ends and where its derivative
+// is zero
+fn extent<S: Scalar, F: Fn(S) -> S>(value: F, critical_points: &[S]) -> (S, S) {
+    let mut min = value(S::from_i32(0));
+    let mut max = min;
+    for &t in [S::from_i32(1)].iter().chain(critical_points.iter()) {
+        let value = value(t);
+        if value < min {
+            min = value;
+        }
+        if value > max {
+            max = value;
+        }
+    }
+    (min, max)
+}
+
+// The direction of the chord, or of the first of the other directions that isn't zero if the curve is closed
+fn chord_axis<T: InnerProductSpace>(directions: &[T], fallback: T) -> T {
+    directions.iter()
+        .copied()
+        .find(|&direction| direction.inner_product(direction) > T::Scalar::from_i32(0))
+        .map(|direction| direction / direction.inner_product(direction).sqrt())
+        .unwrap_or(fallback)
+}
+
+// Two orthonormal axes, the first along the chord
+fn chord_axes_2d<S: Scalar>(directions: &[Vector2<S>]) -> [Vector2<S>; 2] {
+    let x = chord_axis(directions, Vector2::new(S::from_i32(1), S::from_i32(0)));
+    [x, Vector2::new(-x.y, x.x)]
+}
+
+// Three orthonormal axes, the first along the chord and the second toward the direction that bends the curve the most
+// away from it
+fn chord_axes_3d<S: Scalar>(directions: &[Vector3<S>]) -> [Vector3<S>; 3] {
+    let x = chord_axis(directions, Vector3::new(S::from_i32(1), S::from_i32(0), S::from_i32(0)));
+
+    let mut y = Vector3::zero();
+    let mut best = S::from_i32(0);
+    for &direction in directions.iter() {
+        let rejection = direction - x * direction.inner_product(x);
+        let length = rejection.inner_product(rejection);
+        if length > best {
+            y = rejection;
+            best = length;
+        }
+    }
+    if best == S::from_i32(0) {
+        // the curve is straight, so any perpendicular axis will do
+        let abs = x.abs();
+        let axis = if abs.x <= abs.y && abs.x <= abs.z {
+            Vector3::new(S::from_i32(1), S::from_i32(0), S::from_i32(0))
+        } else if abs.y <= abs.z {
+            Vector3::new(S::from_i32(0), S::from_i32(1), S::from_i32(0))
+        } else {
+            Vector3::new(S::from_i32(0), S::from_i32(0), S::from_i32(1))
+        };
+        y = x.cross(axis);
+    }
+    let y = y.normalize();
+
+    [x, y, x.cross(y)]
+}
+
+impl<T: InnerProductSpace> QuadraticCurve<T> {
+    // The parameters between 0 and 1 where the derivative of the curve is perpendicular to the direction, which are
+    // the extrema of the curve along it
+    pub fn extrema(&self, direction: T) -> Roots<T::Scalar> {
+        let b = self.x1().inner_product(direction);
+        let c = self.x2().inner_product(direction);
+        solve_linear_in(c * T::Scalar::from_i32(2), b, T::Scalar::from_i32(0), T::Scalar::from_i32(1))
+    }
+
+    // The smallest and largest values of the inner product of the curve with the direction
+    pub fn extent(&self, direction: T) -> (T::Scalar, T::Scalar) {
+        let a = self.x0().inner_product(direction);
+        let b = self.x1().inner_product(direction);
+        let c = self.x2().inner_product(direction);
+        extent(|t| a + (b + c * t) * t, self.extrema(direction).as_slice())
+    }
+}
+
+impl<T: InnerProductSpace> CubicCurve<T> {
+    // The parameters between 0 and 1 where the derivative of the curve is perpendicular to the direction, which are
+    // the extrema of the curve along it and any points where it only pauses
+    pub fn extrema(&self, direction: T) -> Roots<T::Scalar> {
+        let b = self.x1().inner_product(direction);
+        let c = self.x2().inner_product(direction);
+        let d = self.x3().inner_product(direction);
+        solve_quadratic_in(
+            d * T::Scalar::from_i32(3),
+            c * T::Scalar::from_i32(2),
+            b,
+            T::Scalar::from_i32(0),
+            T::Scalar::from_i32(1),
+        )
+    }
+
+    // The smallest and largest values of the inner product of the curve with the direction
+    pub fn extent(&self, direction: T) -> (T::Scalar, T::Scalar) {
+        let a = self.x0().inner_product(direction);
+        let b = self.x1().inner_product(direction);
+        let c = self.x2().inner_product(direction);
+        let d = self.x3().inner_product(direction);
+        extent(|t| a + (b + (c + d * t) * t) * t, self.extrema(direction).as_slice())
+    }
+}
+
+impl<S: Scalar> QuadraticCurve<Vector2<S>> {
+    pub fn bounding_box(&self) -> BoundingBox<Vector2<S>> {
+        let x = self.extent(Vector2::new(S::from_i32(1), S::from_i32(0)));
+        let y = self.extent(Vector2::new(S::from_i32(0), S::from_i32(1)));
+        BoundingBox::new(Vector2::new(x.0, y.0), Vector2::new(x.1, y.1))
+    }
+
+    // The bounding box aligned to the line from the start of the curve to its end
+    pub fn oriented_bounding_box(&self) -> OrientedBoundingBox<Vector2<S>, 2> {
+        let axes = chord_axes_2d(&[self.x1() + self.x2(), self.x1(), self.x2()]);
+        OrientedBoundingBox::from_extents(axes, |axis| self.extent(axis))
+    }
+}
+
+impl<S: Scalar> QuadraticCurve<Vector3<S>> {
+    pub fn bounding_box(&self) -> BoundingBox<Vector3<S>> {
+        let x = self.extent(Vector3::new(S::from_i32(1), S::from_i32(0), S::from_i32(0)));
+        let y = self.extent(Vector3::new(S::from_i32(0), S::from_i32(1), S::from_i32(0)));
+        let z = self.extent(Vector3::new(S::from_i32(0), S::from_i32(0), S::from_i32(1)));
+        BoundingBox::new(Vector3::new(x.0, y.0, z.0), Vector3::new(x.1, y.1, z.1))
+    }
+
+    // The bounding box aligned to the line from the start of the curve to its end and to the plane of the curve
+    pub fn oriented_bounding_box(&self) -> OrientedBoundingBox<Vector3<S>, 3> {
+        let axes = chord_axes_3d(&[self.x1() + self.x2(), self.x1(), self.x2()]);
+        OrientedBoundingBox::from_extents(axes, |axis| self.extent(axis))
+    }
+}
+
+impl<S: Scalar> CubicCurve<Vector2<S>> {
+    pub fn bounding_box(&self) -> BoundingBox<Vector2<S>> {
+        let x = self.extent(Vector2::new(S::from_i32(1), S::from_i32(0)));
+        let y = self.extent(Vector2::new(S::from_i32(0), S::from_i32(1)));
+        BoundingBox::new(Vector2::new(x.0, y.0), Vector2::new(x.1, y.1))
+    }
+
+    // The bounding box aligned to the line from the start of the curve to its end
+    pub fn oriented_bounding_box(&self) -> OrientedBoundingBox<Vector2<S>, 2> {
+        let axes = chord_axes_2d(&[self.x1() + self.x2() + self.x3(), self.x1(), self.x2(), self.x3()]);
+        OrientedBoundingBox::from_extents(axes, |axis| self.extent(axis))
+    }
+}
+
+impl<S: Scalar> CubicCurve<Vector3<S>> {
+    pub fn bounding_box(&self) -> BoundingBox<Vector3<S>> {
+        let x = self.extent(Vector3::new(S::from_i32(1), S::from_i32(0), S::from_i32(0)));
+        let y = self.extent(Vector3::new(S::from_i32(0), S::from_i32(1), S::from_i32(0)));
+        let z = self.extent(Vector3::new(S::from_i32(0), S::from_i32(0), S::from_i32(1)));
+        BoundingBox::new(Vector3::new(x.0, y.0, z.0), Vector3::new(x.1, y.1, z.1))
+    }
+
+    // The bounding box aligned to the line from the start of the curve to its end
+    pub fn oriented_bounding_box(&self) -> OrientedBoundingBox<Vector3<S>, 3> {
+        let axes = chord_axes_3d(&[self.x1() + self.x2() + self.x3(), self.x1(), self.x2(), self.x3()]);
+        OrientedBoundingBox::from_extents(axes, |axis| self.extent(axis))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod bounding_box {
+        use crate::{
+            CubicCurve,
+            InnerProductSpace,
+            QuadraticCurve,
+            Vector2,
+            Vector3,
+        };
+        use approx::{
+            assert_abs_diff_eq,
+            assert_ulps_eq,
+        };
+
+        #[test]
+        fn quadratic() {
+            let curve = QuadraticCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32), Vector2::new(3f32, -1f32));
+            let bounds = curve.bounding_box();
+            assert_eq!(bounds.min().x, 0f32);
+            assert_eq!(bounds.max().x, 3f32);
+            assert_eq!(bounds.min().y, -1f32);
+            // y = 4t - 5t^2 is largest at t = 0.4
+            assert_ulps_eq!(bounds.max().y, 0.8f32);
+
+            let curve = QuadraticCurve::from_bezier(Vector3::new(0f32, 0f32, 1f32), Vector3::new(2f32, 2f32, 2f32), Vector3::new(0f32, 1f32, -1f32));
+            let bounds = curve.bounding_box();
+            assert_eq!(bounds.min(), Vector3::new(0f32, 0f32, -1f32));
+            assert_ulps_eq!(bounds.max().x, 1f32);
+            assert_ulps_eq!(bounds.max().y, 4f32 / 3f32);
+            assert_ulps_eq!(bounds.max().z, 1.25f32);
+        }
+
+        #[test]
+        fn cubic() {
+            let curve = CubicCurve::from_bezier(
+                Vector2::new(0f32, 0f32),
+                Vector2::new(-1f32, 3f32),
+                Vector2::new(4f32, -2f32),
+                Vector2::new(3f32, 1f32),
+            );
+            let bounds = curve.bounding_box();
+            let mut min = curve.evaluate(0f32);
+            let mut max = min;
+            for i in 0..=10000 {
+                let point = curve.evaluate(i as f32 / 10000f32);
+                min = min.component_min(point);
+                max = max.component_max(point);
+            }
+            assert_abs_diff_eq!(bounds.min().x, min.x, epsilon = 0.00001f32);
+            assert_abs_diff_eq!(bounds.min().y, min.y, epsilon = 0.00001f32);
+            assert_abs_diff_eq!(bounds.max().x, max.x, epsilon = 0.00001f32);
+            assert_abs_diff_eq!(bounds.max().y, max.y, epsilon = 0.00001f32);
+            // the curve overshoots both ends
+            assert!(bounds.min().x < 0f32 && bounds.max().x > 3f32);
+
+            let curve = CubicCurve::from_bezier(
+                Vector3::new(0f32, 0f32, 0f32),
+                Vector3::new(1f32, 2f32, -1f32),
+                Vector3::new(2f32, -2f32, 3f32),
+                Vector3::new(3f32, 0f32, 0f32),
+            );
+            let bounds = curve.bounding_box();
+            for i in 0..=1000 {
+                let point = curve.evaluate(i as f32 / 1000f32);
+                assert_eq!(point.component_max(bounds.min()), point);
+                assert_eq!(point.component_min(bounds.max()), point);
+            }
+        }
+
+        #[test]
+        fn oriented() {
+            // a curve along the diagonal has a much smaller box aligned to its chord
+            let curve = CubicCurve::from_bezier(
+                Vector2::new(0f32, 0f32),
+                Vector2::new(1f32, 1.2f32),
+                Vector2::new(2f32, 1.8f32),
+                Vector2::new(3f32, 3f32),
+            );
+            let oriented = curve.oriented_bounding_box();
+            let axes = oriented.axes();
+            assert_ulps_eq!(axes[0].x, 0.5f32.sqrt());
+            assert_ulps_eq!(axes[0].y, 0.5f32.sqrt());
+            assert_eq!(axes[0].inner_product(axes[1]), 0f32);
+
+            let bounds = curve.bounding_box();
+            let area = |min: [f32; 2], max: [f32; 2]| (max[0] - min[0]) * (max[1] - min[1]);
+            let aligned_area = (bounds.max().x - bounds.min().x) * (bounds.max().y - bounds.min().y);
+            assert!(area(oriented.min(), oriented.max()) < aligned_area / 5f32);
+
+            for i in 0..=1000 {
+                let point = curve.evaluate(i as f32 / 1000f32);
+                for (axis, (&min, &max)) in axes.iter().zip(oriented.min().iter().zip(oriented.max().iter())) {
+                    let value = point.inner_product(*axis);
+                    assert!(min - 0.00001f32 <= value && value <= max + 0.00001f32);
+                }
+            }
+            assert!(oriented.contains(curve.evaluate(0.5f32)));
+            assert!(!oriented.contains(Vector2::new(0f32, 3f32)));
+
+            // a planar curve has no thickness out of its plane
+            let curve = QuadraticCurve::from_bezier(Vector3::new(0f32, 0f32, 0f32), Vector3::new(1f32, 2f32, 2f32), Vector3::new(3f32, 0f32, 0f32));
+            let oriented = curve.oriented_bounding_box();
+            assert_abs_diff_eq!(oriented.min()[2], oriented.max()[2], epsilon = 0.00001f32);
+            assert_abs_diff_eq!(oriented.max()[0] - oriented.min()[0], 3f32, epsilon = 0.00001f32);
+            assert_abs_diff_eq!(oriented.max()[1] - oriented.min()[1], 2f32.sqrt(), epsilon = 0.00001f32);
+        }
+    }
+}
\ No newline at end of file
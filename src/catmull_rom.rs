@@ -0,0 +1,233 @@
+use crate::{
+    CubicCurve,
+    InnerProductSpace,
+    Scalar,
+    Spline,
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CatmullRomParameterization {
+    // alpha = 0
+    Uniform,
+    // alpha = 0.5, which never forms cusps or self-intersections within a segment
+    Centripetal,
+    // alpha = 1
+    Chordal,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CatmullRomBoundary<T: InnerProductSpace> {
+    // the last point connects back to the first
+    Closed,
+    // the missing neighbors of the end points are their neighbors reflected through them
+    Reflected,
+    // the missing neighbors of the end points are the end points themselves, which stops the curve at each end
+    Duplicated,
+    // the derivatives at the first and last points
+    Tangents(T, T),
+}
+
+impl CatmullRomParameterization {
+    fn interval<T: InnerProductSpace>(self, p0: T, p1: T) -> T::Scalar {
+        let distance = (p1 - p0).inner_product(p1 - p0).sqrt();
+        match self {
+            CatmullRomParameterization::Uniform => T::Scalar::from_i32(1),
+            CatmullRomParameterization::Centripetal => distance.sqrt(),
+            CatmullRomParameterization::Chordal => distance,
+        }
+    }
+}
+
+// The point at index i, extended past the ends of the points as the boundary describes
+pub fn boundary_point<T: InnerProductSpace>(points: &[T], boundary: CatmullRomBoundary<T>, i: isize) -> T {
+    let n = points.len();
+    if let CatmullRomBoundary::Closed = boundary {
+        points[i.rem_euclid(n as isize) as usize]
+    } else if i < 0 {
+        match boundary {
+            CatmullRomBoundary::Reflected => points[0] * T::Scalar::from_i32(2) - points[1],
+            _ => points[0],
+        }
+    } else if i as usize >= n {
+        match boundary {
+            CatmullRomBoundary::Reflected => points[n - 1] * T::Scalar::from_i32(2) - points[n - 2],
+            _ => points[n - 1],
+        }
+    } else {
+        points[i as usize]
+    }
+}
+
+// The derivative of the segment from p1 to p2 at p1, scaled to the segment's own parameter
+fn tangent<T: InnerProductSpace>(p0: T, p1: T, p2: T, d0: T::Scalar, d1: T::Scalar) -> T {
+    // coincident points have an interval of zero and contribute nothing
+    let slope = |a: T, b: T, d: T::Scalar| if d == T::Scalar::from_i32(0) {
+        T::zero()
+    } else {
+        (b - a) / d
+    };
+
+    (p2 - p1) + (slope(p0, p1, d0) - slope(p0, p2, d0 + d1)) * d1
+}
+
+impl<T: InnerProductSpace> Spline<CubicCurve<T>> {
+    // Builds a spline that passes through every point, with one segment between each pair of consecutive points
+    pub fn catmull_rom(points: &[T], parameterization: CatmullRomParameterization, boundary: CatmullRomBoundary<T>) -> Spline<CubicCurve<T>> {
+        let n = points.len();
+        if n < 2 {
+            return Spline::new(Vec::new());
+        }
+
+        let closed = matches!(boundary, CatmullRomBoundary::Closed);
+        let point = |i: isize| boundary_point(points, boundary, i);
+        let interval = |i: isize| parameterization.interval(point(i), point(i + 1));
+
+        let segment_count = if closed { n } else { n - 1 };
+        let mut segments = Vec::with_capacity(segment_count);
+        for index in 0..segment_count {
+            let i = index as isize;
+            let (p0, p1, p2, p3) = (point(i - 1), point(i), point(i + 1), point(i + 2));
+            let (d0, d1, d2) = (interval(i - 1), interval(i), interval(i + 1));
+
+            let mut m1 = tangent(p0, p1, p2, d0, d1);
+            // the tangent at p2 is found by walking the points in reverse
+            let mut m2 = T::zero() - tangent(p3, p2, p1, d2, d1);
+
+            if let CatmullRomBoundary::Tangents(start, end) = boundary {
+                if index == 0 {
+                    m1 = start;
+                }
+                if index == segment_count - 1 {
+                    m2 = end;
+                }
+            }
+
+            segments.push(CubicCurve::from_hermite(p1, m1, p2, m2));
+        }
+
+        Spline::new(segments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod catmull_rom {
+        use crate::{
+            CatmullRomBoundary,
+            CatmullRomParameterization,
+            CubicCurve,
+            Spline,
+            Vector2,
+        };
+        use approx::{
+            assert_abs_diff_eq,
+            assert_ulps_eq,
+        };
+
+        fn points() -> [Vector2<f32>; 5] {
+            [
+                Vector2::new(0f32, 0f32),
+                Vector2::new(1f32, 2f32),
+                Vector2::new(1.5f32, 2f32),
+                Vector2::new(4f32, -1f32),
+                Vector2::new(5f32, 0f32),
+            ]
+        }
+
+        fn assert_interpolates(spline: &Spline<CubicCurve<Vector2<f32>>>, points: &[Vector2<f32>]) {
+            for (i, &point) in points.iter().enumerate() {
+                let evaluated = spline.evaluate(i as f32).unwrap();
+                assert_abs_diff_eq!(evaluated.x, point.x, epsilon = 0.00001f32);
+                assert_abs_diff_eq!(evaluated.y, point.y, epsilon = 0.00001f32);
+            }
+        }
+
+        fn assert_g1(spline: &Spline<CubicCurve<Vector2<f32>>>) {
+            let segments = spline.segments();
+            for pair in segments.windows(2) {
+                let left = pair[0].evaluate_first_derivative(1f32);
+                let right = pair[1].evaluate_first_derivative(0f32);
+                assert_abs_diff_eq!(left.normalize().cross(right.normalize()), 0f32, epsilon = 0.00001f32);
+                assert!(left.x * right.x + left.y * right.y > 0f32);
+            }
+        }
+
+        #[test]
+        fn uniform() {
+            let points = points();
+            let spline = Spline::catmull_rom(&points, CatmullRomParameterization::Uniform, CatmullRomBoundary::Reflected);
+            assert_eq!(spline.len(), 4);
+            assert_interpolates(&spline, &points);
+
+            // the tangent at each interior point is half the difference of its neighbors
+            for i in 1..4 {
+                let expected = (points[i + 1] - points[i - 1]) / 2f32;
+                assert_eq!(spline.segments()[i - 1].evaluate_first_derivative(1f32), expected);
+                assert_eq!(spline.segments()[i].evaluate_first_derivative(0f32), expected);
+            }
+
+            // reflecting the end points makes the end tangents the differences to their neighbors
+            assert_eq!(spline.evaluate_derivative(0f32).unwrap(), points[1] - points[0]);
+            assert_eq!(spline.evaluate_derivative(4f32).unwrap(), points[4] - points[3]);
+        }
+
+        #[test]
+        fn centripetal() {
+            let points = points();
+            let spline = Spline::catmull_rom(&points, CatmullRomParameterization::Centripetal, CatmullRomBoundary::Reflected);
+            assert_eq!(spline.len(), 4);
+            assert_interpolates(&spline, &points);
+            assert_g1(&spline);
+        }
+
+        #[test]
+        fn chordal() {
+            let points = points();
+            let spline = Spline::catmull_rom(&points, CatmullRomParameterization::Chordal, CatmullRomBoundary::Reflected);
+            assert_eq!(spline.len(), 4);
+            assert_interpolates(&spline, &points);
+            assert_g1(&spline);
+
+            // for evenly spaced points every parameterization is the same
+            let points = [Vector2::new(0f32, 0f32), Vector2::new(1f32, 0f32), Vector2::new(1f32, 1f32), Vector2::new(0f32, 1f32)];
+            let uniform = Spline::catmull_rom(&points, CatmullRomParameterization::Uniform, CatmullRomBoundary::Closed);
+            let chordal = Spline::catmull_rom(&points, CatmullRomParameterization::Chordal, CatmullRomBoundary::Closed);
+            assert_eq!(uniform, chordal);
+        }
+
+        #[test]
+        fn closed() {
+            let points = points();
+            for &parameterization in &[CatmullRomParameterization::Uniform, CatmullRomParameterization::Centripetal, CatmullRomParameterization::Chordal] {
+                let spline = Spline::catmull_rom(&points, parameterization, CatmullRomBoundary::Closed);
+                assert_eq!(spline.len(), 5);
+                assert_interpolates(&spline, &[points[0], points[1], points[2], points[3], points[4], points[0]]);
+                assert_g1(&Spline::new(vec![spline.segments()[4], spline.segments()[0]]));
+                assert_g1(&spline);
+            }
+        }
+
+        #[test]
+        fn end_conditions() {
+            let points = points();
+            let spline = Spline::catmull_rom(&points, CatmullRomParameterization::Centripetal, CatmullRomBoundary::Duplicated);
+            assert_interpolates(&spline, &points);
+            assert_ulps_eq!(spline.evaluate_derivative(0f32).unwrap().length(), 0f32);
+            assert_ulps_eq!(spline.evaluate_derivative(4f32).unwrap().length(), 0f32);
+
+            let start = Vector2::new(0f32, 3f32);
+            let end = Vector2::new(-1f32, 0f32);
+            let spline = Spline::catmull_rom(&points, CatmullRomParameterization::Chordal, CatmullRomBoundary::Tangents(start, end));
+            assert_interpolates(&spline, &points);
+            assert_eq!(spline.evaluate_derivative(0f32).unwrap(), start);
+            assert_eq!(spline.evaluate_derivative(4f32).unwrap(), end);
+
+            let spline = Spline::catmull_rom(&points[..2], CatmullRomParameterization::Uniform, CatmullRomBoundary::Reflected);
+            assert_eq!(spline.len(), 1);
+            assert_eq!(spline.evaluate(0.5f32).unwrap(), Vector2::new(0.5f32, 1f32));
+
+            let spline = Spline::catmull_rom(&points[..1], CatmullRomParameterization::Uniform, CatmullRomBoundary::Reflected);
+            assert!(spline.is_empty());
+        }
+    }
+}
\ No newline at end of file
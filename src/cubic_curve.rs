@@ -1,124 +1,381 @@
 use crate::{
     InnerProductSpace,
+    LinearApproximation,
+    LinearCurve,
+    PolynomialCurve,
     QuadraticCurve,
     Scalar,
+    quadrature::integrate,
+    roots::{
+        solve_cubic_in,
+        solve_polynomial,
+    },
 };
 
+// The most equal pieces that a curve is split into
+pub(crate) const MAX_PIECES: i32 = 1 << 16;
+
+// A PolynomialCurve of degree three, x0 + x1 t + x2 t^2 + x3 t^3
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct CubicCurve<T: InnerProductSpace> {
-    x0: T,
-    x1: T,
-    x2: T,
-    x3: T,
+    curve: PolynomialCurve<T, 4>,
 }
 
 impl<T: InnerProductSpace> CubicCurve<T> {
     pub fn new(x0: T, x1: T, x2: T, x3: T) -> CubicCurve<T> {
         CubicCurve {
-            x0,
-            x1,
-            x2,
-            x3,
+            curve: PolynomialCurve::new([x0, x1, x2, x3]),
         }
     }
 
     pub fn x0(&self) -> T {
-        self.x0
+        self.curve.coefficients()[0]
     }
 
     pub fn x1(&self) -> T {
-        self.x1
+        self.curve.coefficients()[1]
     }
 
     pub fn x2(&self) -> T {
-        self.x2
+        self.curve.coefficients()[2]
     }
 
     pub fn x3(&self) -> T {
-        self.x3
+        self.curve.coefficients()[3]
     }
 
     pub fn evaluate(&self, t: T::Scalar) -> T {
-        self.x0 + (self.x1 + (self.x2 + self.x3 * t) * t) * t
+        self.curve.evaluate(t)
     }
 
     pub fn evaluate_first_derivative(&self, t: T::Scalar) -> T {
-        self.x1 + self.x2 * t * T::Scalar::from_i32(2) + self.x3 * t * t * T::Scalar::from_i32(3)
+        self.x1() + self.x2() * t * T::Scalar::from_i32(2) + self.x3() * t * t * T::Scalar::from_i32(3)
     }
 
     pub fn evaluate_second_derivative(&self, t: T::Scalar) -> T {
-        self.x2 * T::Scalar::from_i32(2) + self.x3 * t * T::Scalar::from_i32(6)
+        self.x2() * T::Scalar::from_i32(2) + self.x3() * t * T::Scalar::from_i32(6)
     }
 
     pub fn evaluate_third_derivative(&self) -> T {
-        self.x3 * T::Scalar::from_i32(6)
+        self.x3() * T::Scalar::from_i32(6)
     }
 
     pub fn from_bezier(b0: T, b1: T, b2: T, b3: T) -> CubicCurve<T> {
-        CubicCurve {
-            x0: b0,
-            x1: b0 * T::Scalar::from_i32(-3) + b1 * T::Scalar::from_i32(3),
-            x2: b0 * T::Scalar::from_i32(3) + b1 * T::Scalar::from_i32(-6) + b2 * T::Scalar::from_i32(3),
-            x3: b0 * T::Scalar::from_i32(-1) + b1 * T::Scalar::from_i32(3) + b2 * T::Scalar::from_i32(-3) + b3,
-        }
+        CubicCurve::new(
+            b0,
+            b0 * T::Scalar::from_i32(-3) + b1 * T::Scalar::from_i32(3),
+            b0 * T::Scalar::from_i32(3) + b1 * T::Scalar::from_i32(-6) + b2 * T::Scalar::from_i32(3),
+            b0 * T::Scalar::from_i32(-1) + b1 * T::Scalar::from_i32(3) + b2 * T::Scalar::from_i32(-3) + b3,
+        )
     }
 
     pub fn to_bezier(&self) -> (T, T, T, T) {
         (
-            self.x0,
-            self.x0 + self.x1 / T::Scalar::from_i32(3),
-            self.x0 + self.x1 * T::Scalar::from_i32(2) / T::Scalar::from_i32(3) + self.x2 / T::Scalar::from_i32(3),
-            self.x0 + self.x1 + self.x2 + self.x3,
+            self.x0(),
+            self.x0() + self.x1() / T::Scalar::from_i32(3),
+            self.x0() + self.x1() * T::Scalar::from_i32(2) / T::Scalar::from_i32(3) + self.x2() / T::Scalar::from_i32(3),
+            self.x0() + self.x1() + self.x2() + self.x3(),
         )
     }
 
-    pub fn subdivide(&self, u: T::Scalar) -> (CubicCurve<T>, CubicCurve<T>) {
-        let x1u = self.x1 * u;
-        let x2u = self.x2 * u;
-        let x2u2 = x2u * u;
-        let x3u = self.x3 * u;
-        let x3u2 = x3u * u;
-        let x3u3 = x3u2 * u;
-        let w = T::Scalar::from_i32(1) - u;
-        let w2 = w * w;
+    // The curve that starts at p0 with derivative m0 and ends at p1 with derivative m1
+    pub fn from_hermite(p0: T, m0: T, p1: T, m1: T) -> CubicCurve<T> {
+        CubicCurve::new(
+            p0,
+            m0,
+            p0 * T::Scalar::from_i32(-3) + p1 * T::Scalar::from_i32(3) + m0 * T::Scalar::from_i32(-2) - m1,
+            p0 * T::Scalar::from_i32(2) + p1 * T::Scalar::from_i32(-2) + m0 + m1,
+        )
+    }
+
+    pub fn to_hermite(&self) -> (T, T, T, T) {
+        (
+            self.x0(),
+            self.x1(),
+            self.x0() + self.x1() + self.x2() + self.x3(),
+            self.x1() + self.x2() * T::Scalar::from_i32(2) + self.x3() * T::Scalar::from_i32(3),
+        )
+    }
 
+    pub fn subdivide(&self, u: T::Scalar) -> (CubicCurve<T>, CubicCurve<T>) {
+        let (left, right) = self.curve.subdivide(u);
         (
-            CubicCurve::new(
-                self.x0,
-                x1u,
-                x2u2,
-                x3u3,
-            ),
-            CubicCurve::new(
-                self.x0 + x1u + x2u2 + x3u3,
-                (self.x1 + x2u * T::Scalar::from_i32(2) + x3u2 * T::Scalar::from_i32(3)) * w,
-                (self.x2 + x3u * T::Scalar::from_i32(3)) * w2,
-                self.x3 * w2 * w,
-            )
+            CubicCurve {
+                curve: left,
+            },
+            CubicCurve {
+                curve: right,
+            },
         )
     }
 
+    pub fn speed(&self, u: T::Scalar) -> T::Scalar {
+        let derivative = self.evaluate_first_derivative(u);
+        derivative.inner_product(derivative).sqrt()
+    }
+
+    pub fn length(&self, u: T::Scalar, tolerance: T::Scalar) -> T::Scalar {
+        if self.x3().inner_product(self.x3()) == T::Scalar::from_i32(0) {
+            // the curve is actually a quadratic, so use the closed-form solution
+            QuadraticCurve::new(self.x0(), self.x1(), self.x2()).length(u)
+        } else {
+            // the speed has kinks where it reaches zero, so split the integral at the local extrema of the speed
+            let a = self.x3().inner_product(self.x3()) * T::Scalar::from_i32(9);
+            let b = self.x2().inner_product(self.x3()) * T::Scalar::from_i32(9);
+            let c = self.x1().inner_product(self.x3()) * T::Scalar::from_i32(3) + self.x2().inner_product(self.x2()) * T::Scalar::from_i32(2);
+            let d = self.x1().inner_product(self.x2());
+
+            let (lo, hi) = if u >= T::Scalar::from_i32(0) {
+                (T::Scalar::from_i32(0), u)
+            } else {
+                (u, T::Scalar::from_i32(0))
+            };
+
+            let extrema = solve_cubic_in(a, b, c, d, lo, hi);
+            let piece_tolerance = tolerance / T::Scalar::from_i32(extrema.len() as i32 + 1);
+            let mut length = T::Scalar::from_i32(0);
+            let mut start = lo;
+            for end in extrema.into_iter().chain(Some(hi)) {
+                length += integrate(|t| self.speed(t), start, end, piece_tolerance);
+                start = end;
+            }
+
+            if u >= T::Scalar::from_i32(0) {
+                length
+            } else {
+                -length
+            }
+        }
+    }
+
+    pub fn parameterize(&self, v: T::Scalar, tolerance: T::Scalar, max_iters: usize) -> Option<T::Scalar> {
+        if self.x1().inner_product(self.x1()) == T::Scalar::from_i32(0) && self.x2().inner_product(self.x2()) == T::Scalar::from_i32(0) && self.x3().inner_product(self.x3()) == T::Scalar::from_i32(0) {
+            None
+        } else {
+            let mut l = T::Scalar::from_i32(0);
+            let mut fl = -v;
+            let mut r = T::Scalar::from_i32(1);
+            let mut fr = self.length(r, tolerance) - v;
+            let mut x = l - (r - l) * fl / (fr - fl);
+            // which end of the bracket moved last, -1 for the left and 1 for the right
+            let mut side = 0;
+
+            if fl > T::Scalar::from_i32(0) {
+                Some(T::Scalar::from_i32(0))
+            } else if fr < T::Scalar::from_i32(0) {
+                Some(T::Scalar::from_i32(1))
+            } else {
+                for _ in 0..max_iters {
+                    let fx = self.length(x, tolerance) - v;
+                    if fx.abs() < tolerance {
+                        break;
+                    }
+
+                    // when the same end of the bracket is kept twice in a row, shrink its value so that the linear
+                    // approximation can't stall near cusps, where the length is very flat on one side (the
+                    // Anderson-Bjorck variant of the false position method)
+                    let scale = |f: T::Scalar, previous: T::Scalar| {
+                        let m = T::Scalar::from_i32(1) - fx / previous;
+                        if m > T::Scalar::from_i32(0) {
+                            f * m
+                        } else {
+                            f / T::Scalar::from_i32(2)
+                        }
+                    };
+                    if fx > T::Scalar::from_i32(0) {
+                        // root is to the left
+                        if side == 1 {
+                            fl = scale(fl, fr);
+                        }
+                        r = x;
+                        fr = fx;
+                        side = 1;
+                    } else {
+                        // root is to the right
+                        if side == -1 {
+                            fr = scale(fr, fl);
+                        }
+                        l = x;
+                        fl = fx;
+                        side = -1;
+                    }
+
+                    // approximate next location of x using Newton's method
+                    x -= fx / self.speed(x);
+                    if !(x >= l && x <= r) {
+                        // if it falls out of range (or the speed is zero), use a linear approximation instead
+                        x = l - (r - l) * fl / (fr - fl);
+                    }
+                }
+
+                Some(x)
+            }
+        }
+    }
+
+    pub fn nearest_point(&self, q: T) -> (T::Scalar, T::Scalar) {
+        let a = self.x3().inner_product(self.x3());
+        let b = self.x2().inner_product(self.x3()) * T::Scalar::from_i32(2);
+        let c = self.x1().inner_product(self.x3()) * T::Scalar::from_i32(2) + self.x2().inner_product(self.x2());
+        let d = (self.x0() - q).inner_product(self.x3()) * T::Scalar::from_i32(2) + self.x1().inner_product(self.x2()) * T::Scalar::from_i32(2);
+        let e = (self.x0() - q).inner_product(self.x2()) * T::Scalar::from_i32(2) + self.x1().inner_product(self.x1());
+        let f = (self.x0() - q).inner_product(self.x1()) * T::Scalar::from_i32(2);
+        let g = (self.x0() - q).inner_product(self.x0() - q);
+
+        let distance = |t| (g + t * (f + t * (e + t * (d + t * (c + t * (b + a * t)))))).abs().sqrt();
+
+        let left_value = T::Scalar::from_i32(0);
+        let left_distance = distance(left_value);
+        let right_value = T::Scalar::from_i32(1);
+        let right_distance = distance(right_value);
+
+        let (mut best_value, mut best_distance) = if left_distance <= right_distance {
+            (left_value, left_distance)
+        } else {
+            (right_value, right_distance)
+        };
+
+        let coefficients = [
+            T::Scalar::from_i32(6) * a,
+            T::Scalar::from_i32(5) * b,
+            T::Scalar::from_i32(4) * c,
+            T::Scalar::from_i32(3) * d,
+            T::Scalar::from_i32(2) * e,
+            f,
+        ];
+        let roots = solve_polynomial(&coefficients, (T::Scalar::from_i32(0), T::Scalar::from_i32(1)));
+        for &root in roots.iter().flatten() {
+            let root_distance = distance(root);
+            if root_distance < best_distance {
+                best_value = root;
+                best_distance = root_distance;
+            }
+        }
+
+        (best_value, best_distance)
+    }
+
     pub fn error_minimizing_quadratic_approximation(&self) -> QuadraticCurve<T> {
         QuadraticCurve::new(
-            self.x0 + self.x3 / T::Scalar::from_i32(32),
-            self.x1 - self.x3 * T::Scalar::from_i32(9) / T::Scalar::from_i32(16),
-            self.x2 + self.x3 * T::Scalar::from_i32(3) / T::Scalar::from_i32(2),
+            self.x0() + self.x3() / T::Scalar::from_i32(32),
+            self.x1() - self.x3() * T::Scalar::from_i32(9) / T::Scalar::from_i32(16),
+            self.x2() + self.x3() * T::Scalar::from_i32(3) / T::Scalar::from_i32(2),
         )
     }
 
     pub fn continuity_preseving_quadratic_approximation(&self) -> QuadraticCurve<T> {
         QuadraticCurve::new(
-            self.x0,
-            self.x1 - self.x3 / T::Scalar::from_i32(2),
-            self.x2 + self.x3 * T::Scalar::from_i32(3) / T::Scalar::from_i32(2),
+            self.x0(),
+            self.x1() - self.x3() / T::Scalar::from_i32(2),
+            self.x2() + self.x3() * T::Scalar::from_i32(3) / T::Scalar::from_i32(2),
         )
     }
+
+    // Splits the curve into the fewest equal pieces whose continuity-preserving approximations are within the tolerance,
+    // which meet with matching derivatives. There are at most MAX_PIECES pieces, so a tolerance that needs more is
+    // exceeded.
+    pub fn to_quadratics(&self, tolerance: T::Scalar) -> Vec<QuadraticCurve<T>> {
+        let count = quadratic_count(self, tolerance);
+        (0..count).map(|i| piece(self, i, count).continuity_preseving_quadratic_approximation()).collect()
+    }
+
+    fn quadratic_approximation(&self, approximation: LinearApproximation) -> QuadraticCurve<T> {
+        match approximation {
+            LinearApproximation::ErrorMinimizing => self.error_minimizing_quadratic_approximation(),
+            LinearApproximation::ContinuityPreserving => self.continuity_preseving_quadratic_approximation(),
+        }
+    }
+
+    // Splits the curve into equal pieces that are within the tolerance of their lines, approximating each piece with a
+    // quadratic and then the quadratic with a line, or returns None if that needs more than MAX_PIECES pieces
+    pub fn to_lines(&self, tolerance: T::Scalar, approximation: LinearApproximation) -> Option<Vec<LinearCurve<T>>> {
+        assert!(tolerance > T::Scalar::from_i32(0), "the tolerance must be positive");
+
+        let quadratic_error = match approximation {
+            LinearApproximation::ErrorMinimizing => T::Scalar::from_i32(1) / T::Scalar::from_i32(32),
+            LinearApproximation::ContinuityPreserving => T::Scalar::from_i32(1) / (T::Scalar::from_i32(12) * T::Scalar::from_i32(3).sqrt()),
+        };
+
+        // each of n pieces has a cubic coefficient of x3 / n^3, and its quadratic has a quadratic coefficient of
+        // x2 + 3 x3 t at the middle of the piece over n^2, which is no longer than at one of the ends of the curve. The
+        // errors of the two approximations add up to at most a / n^3 + b / n^2, which is within the tolerance once n is
+        // at least cbrt(a / tolerance) + sqrt(b / tolerance).
+        let length = |v: T| v.inner_product(v).sqrt();
+        let end = self.x2() + self.x3() * T::Scalar::from_i32(3);
+        let x2 = if length(self.x2()) > length(end) { length(self.x2()) } else { length(end) };
+        let a = length(self.x3()) * quadratic_error;
+        let b = x2 * approximation.error_factor::<T::Scalar>();
+        let count = piece_count((a / tolerance).cbrt() + (b / tolerance).sqrt())?;
+
+        let lines = (0..count)
+            .map(|i| piece(self, i, count).quadratic_approximation(approximation).linear_approximation(approximation))
+            .collect();
+        Some(lines)
+    }
+}
+
+// Rounds a number of pieces up to a whole count of at least one, or None if that is more than MAX_PIECES. Comparing
+// before converting keeps huge counts from overflowing.
+pub(crate) fn piece_count<S: Scalar>(pieces: S) -> Option<i32> {
+    if pieces > S::from_i32(MAX_PIECES) {
+        None
+    } else {
+        Some((-(-pieces).floor().to_i32()).max(1))
+    }
+}
+
+// The number of equal pieces needed for each continuity-preserving approximation to be within the tolerance, up to
+// MAX_PIECES
+pub(crate) fn quadratic_count<T: InnerProductSpace>(curve: &CubicCurve<T>, tolerance: T::Scalar) -> i32 {
+    assert!(tolerance > T::Scalar::from_i32(0), "the tolerance must be positive");
+
+    // each of n pieces has a cubic coefficient of x3 / n^3, so its error is |x3| / (12 sqrt(3) n^3)
+    let error = curve.x3().inner_product(curve.x3()).sqrt() / (T::Scalar::from_i32(12) * T::Scalar::from_i32(3).sqrt());
+    piece_count((error / tolerance).cbrt()).unwrap_or(MAX_PIECES)
+}
+
+// The part of the curve from i / count to (i + 1) / count, reparameterized to [0, 1]
+pub(crate) fn piece<T: InnerProductSpace>(curve: &CubicCurve<T>, i: i32, count: i32) -> CubicCurve<T> {
+    let scale = T::Scalar::from_i32(1) / T::Scalar::from_i32(count);
+    let t = T::Scalar::from_i32(i) * scale;
+    CubicCurve::new(
+        curve.evaluate(t),
+        curve.evaluate_first_derivative(t) * scale,
+        curve.evaluate_second_derivative(t) * scale * scale / T::Scalar::from_i32(2),
+        curve.x3() * scale * scale * scale,
+    )
+}
+
+impl<T: InnerProductSpace> From<CubicCurve<T>> for PolynomialCurve<T, 4> {
+    fn from(curve: CubicCurve<T>) -> Self {
+        curve.curve
+    }
+}
+
+impl<T: InnerProductSpace> From<PolynomialCurve<T, 4>> for CubicCurve<T> {
+    fn from(curve: PolynomialCurve<T, 4>) -> Self {
+        CubicCurve {
+            curve,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     mod cubic_curve {
-        use crate::CubicCurve;
-        use approx::assert_ulps_eq;
+        use crate::{
+            cubic_curve::MAX_PIECES,
+            vector2::Vector2,
+            CubicCurve,
+            InnerProductSpace,
+            LinearApproximation,
+            QuadraticCurve,
+            Scalar,
+        };
+        use approx::{
+            assert_abs_diff_eq,
+            assert_ulps_eq,
+        };
 
         #[test]
         fn evaluate() {
@@ -157,6 +414,39 @@ mod tests {
             }
         }
 
+        #[test]
+        fn hermite() {
+            let test_cases = [
+                (1f32, 1f32, 1f32, 1f32),
+                (2f32, 0f32, 0f32, 1f32),
+                (-1f32, 1f32, 0.5f32, -0.25f32),
+                (5f32, 4f32, 3f32, 2f32),
+                (6f32, -7f32, -9f32, -1000f32),
+            ];
+            for test_case in &test_cases {
+                let curve = CubicCurve::from_hermite(test_case.0, test_case.1, test_case.2, test_case.3);
+                assert_ulps_eq!(curve.evaluate(0f32), test_case.0);
+                assert_ulps_eq!(curve.evaluate_first_derivative(0f32), test_case.1);
+                assert_ulps_eq!(curve.evaluate(1f32), test_case.2);
+                assert_ulps_eq!(curve.evaluate_first_derivative(1f32), test_case.3);
+
+                let hermite = curve.to_hermite();
+                assert_ulps_eq!(hermite.0, test_case.0);
+                assert_ulps_eq!(hermite.1, test_case.1);
+                assert_ulps_eq!(hermite.2, test_case.2);
+                assert_ulps_eq!(hermite.3, test_case.3);
+            }
+
+            // the tangents are three times the offsets of the Bezier control points
+            let curve = CubicCurve::new(1f32, -2f32, 0.5f32, 3f32);
+            let (b0, b1, b2, b3) = curve.to_bezier();
+            let (p0, m0, p1, m1) = curve.to_hermite();
+            assert_ulps_eq!(p0, b0);
+            assert_ulps_eq!(m0, 3f32 * (b1 - b0));
+            assert_ulps_eq!(p1, b3);
+            assert_ulps_eq!(m1, 3f32 * (b3 - b2), max_ulps = 8);
+        }
+
         #[test]
         fn subdivide() {
             let curve = CubicCurve::new(1f32, 1f32, 1f32, 1f32);
@@ -173,6 +463,111 @@ mod tests {
             assert_ulps_eq!(right.x3(), 0.421875f32);
         }
 
+        #[test]
+        fn speed() {
+            let curve = CubicCurve::new(1f32, 1f32, 1f32, 1f32);
+            assert_ulps_eq!(curve.speed(0f32), 1f32);
+            assert_ulps_eq!(curve.speed(0.5f32), 2.75f32);
+            assert_ulps_eq!(curve.speed(1f32), 6f32);
+
+            let curve = CubicCurve::new(Vector2::new(0f32, 0f32), Vector2::new(4f32, 0f32), Vector2::new(0f32, 0f32), Vector2::new(0f32, 1f32));
+            assert_ulps_eq!(curve.speed(0f32), 4f32);
+            assert_ulps_eq!(curve.speed(1f32), 5f32);
+        }
+
+        #[test]
+        fn length() {
+            let curve = CubicCurve::new(0f32, 0f32, 0f32, 1f32);
+            assert_abs_diff_eq!(curve.length(1f32, 0.0001f32), 1f32, epsilon = 0.0001f32);
+            assert_abs_diff_eq!(curve.length(0.5f32, 0.0001f32), 0.125f32, epsilon = 0.0001f32);
+
+            let curve = CubicCurve::new(0f32, 0f32, 0f32, 0f32);
+            assert_ulps_eq!(curve.length(1f32, 0.0001f32), 0f32);
+
+            let quadratic = QuadraticCurve::new(Vector2::new(0f32, 0f32), Vector2::new(1f32, 0f32), Vector2::new(0f32, 1f32));
+            assert_ulps_eq!(quadratic.to_cubic().length(1f32, 0.0001f32), quadratic.length(1f32));
+
+            let curve = CubicCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(0f32, 1f32), Vector2::new(1f32, 1f32), Vector2::new(1f32, 0f32));
+            assert_abs_diff_eq!(curve.length(1f32, 0.0001f32), 2f32, epsilon = 0.0001f32);
+
+            let curve = CubicCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32), Vector2::new(2f32, -1f32), Vector2::new(3f32, 1f32));
+            assert_abs_diff_eq!(curve.length(1f32, 0.0001f32), 3.7488633f32, epsilon = 0.0001f32);
+
+            let (left, right) = curve.subdivide(0.3f32);
+            assert_abs_diff_eq!(left.length(1f32, 0.0001f32), curve.length(0.3f32, 0.0001f32), epsilon = 0.0001f32);
+            assert_abs_diff_eq!(left.length(1f32, 0.0001f32) + right.length(1f32, 0.0001f32), curve.length(1f32, 0.0001f32), epsilon = 0.0001f32);
+        }
+
+        #[test]
+        fn parameterize() {
+            let curve = CubicCurve::new(0f32, 0f32, 0f32, 1f32);
+            check_curve_parameterization(curve);
+
+            let curve = CubicCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(0f32, 1f32), Vector2::new(1f32, 1f32), Vector2::new(1f32, 0f32));
+            check_curve_parameterization(curve);
+
+            let curve = CubicCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32), Vector2::new(2f32, -1f32), Vector2::new(3f32, 1f32));
+            check_curve_parameterization(curve);
+
+            let curve = CubicCurve::new(0f32, 0f32, 0f32, 0f32);
+            check_curve_parameterization(curve);
+
+            let curve = CubicCurve::new(0f32, 1f32, 0f32, 0f32);
+            assert_eq!(curve.parameterize(2f32, 0.001f32, 9), Some(1f32));
+            assert_eq!(curve.parameterize(-1f32, 0.001f32, 9), Some(0f32));
+
+            let test_cases = [
+                (1f32, 1f32, 1f32, 1f32),
+                (2f32, 0f32, 0f32, 1f32),
+                (-1f32, 1f32, 0.5f32, -0.25f32),
+                (5f32, 4f32, 3f32, 2f32),
+                (6f32, -7f32, -9f32, -1000f32),
+            ];
+            for test_case in &test_cases {
+                let curve = CubicCurve::from_bezier(test_case.0, test_case.1, test_case.2, test_case.3);
+                check_curve_parameterization(curve);
+            }
+        }
+
+        fn check_curve_parameterization<T: InnerProductSpace<Scalar = f32>>(curve: CubicCurve<T>) {
+            const SAMPLES: i32 = 100;
+            for i in 0..SAMPLES {
+                let parameter = f32::from_i32(i) / f32::from_i32(SAMPLES);
+                let length = curve.length(parameter, 0.0001f32);
+                if curve.x3().inner_product(curve.x3()) == 0f32 && curve.x2().inner_product(curve.x2()) == 0f32 && curve.x1().inner_product(curve.x1()) == 0f32 {
+                    assert_eq!(curve.parameterize(length, 0.001f32, 9), None);
+                } else {
+                    assert_abs_diff_eq!(curve.length(curve.parameterize(length, 0.001f32, 9).unwrap(), 0.0001f32), length, epsilon = 0.001f32);
+                }
+            }
+        }
+
+        #[test]
+        fn nearest_point() {
+            let curve = CubicCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(0f32, 1f32), Vector2::new(1f32, 1f32), Vector2::new(1f32, 0f32));
+            assert_ulps_eq!(curve.nearest_point(Vector2::new(0.5f32, 1f32)).0, 0.5f32);
+            assert_ulps_eq!(curve.nearest_point(Vector2::new(0.5f32, 1f32)).1, 0.25f32);
+            assert_ulps_eq!(curve.nearest_point(Vector2::new(-0.5f32, -0.5f32)).0, 0f32);
+            assert_ulps_eq!(curve.nearest_point(Vector2::new(1.5f32, -0.5f32)).0, 1f32);
+            assert_ulps_eq!(curve.nearest_point(Vector2::new(1.5f32, -0.5f32)).1, 0.5f32.sqrt());
+            assert_abs_diff_eq!(curve.nearest_point(Vector2::new(0.1f32, 0.3f32)).0, 0.11997592f32, epsilon = 0.00001f32);
+
+            let curve = CubicCurve::new(0f32, 0f32, 0f32, 0f32);
+            assert_ulps_eq!(curve.nearest_point(1f32).0, 0f32);
+            assert_ulps_eq!(curve.nearest_point(0f32).0, 0f32);
+            assert_ulps_eq!(curve.nearest_point(-1f32).0, 0f32);
+            assert_ulps_eq!(curve.nearest_point(-1f32).1, 1f32);
+
+            let curve = CubicCurve::new(Vector2::new(1f32, 1f32), Vector2::new(1f32, 1f32), Vector2::new(0f32, 0f32), Vector2::new(0f32, 0f32));
+            assert_ulps_eq!(curve.nearest_point(Vector2::new(0f32, 0f32)).0, 0f32);
+            assert_ulps_eq!(curve.nearest_point(Vector2::new(2.5f32, 2f32)).0, 1f32);
+            assert_ulps_eq!(curve.nearest_point(Vector2::new(1f32, 2f32)).0, 0.5f32);
+
+            let curve = CubicCurve::new(0f32, 0f32, 0f32, 1f32);
+            assert_ulps_eq!(curve.nearest_point(0.125f32).0, 0.5f32);
+            assert_ulps_eq!(curve.nearest_point(0.125f32).1, 0f32);
+        }
+
         #[test]
         fn error_minimizing_quadratic_approximation() {
             let curve = CubicCurve::new(1f32, 1f32, 1f32, 1f32);
@@ -205,5 +600,95 @@ mod tests {
             assert_ulps_eq!((curve.evaluate(left_max) - quadratic.evaluate(left_max)).abs(), max_error);
             assert_ulps_eq!((curve.evaluate(right_max) - quadratic.evaluate(right_max)).abs(), max_error);
         }
+
+        #[test]
+        fn to_quadratics() {
+            let curve = CubicCurve::from_bezier(
+                Vector2::new(0f32, 0f32),
+                Vector2::new(1f32, 3f32),
+                Vector2::new(4f32, -2f32),
+                Vector2::new(5f32, 1f32),
+            );
+            let error = curve.x3().length() / 12f32 / 3f32.sqrt();
+            for &tolerance in &[1f32, 0.1f32, 0.01f32, 0.001f32] {
+                let quadratics = curve.to_quadratics(tolerance);
+                let n = quadratics.len() as f32;
+
+                // the count is the fewest equal pieces that meet the tolerance
+                assert!(error / (n * n * n) <= tolerance);
+                assert!(n == 1f32 || error / ((n - 1f32) * (n - 1f32) * (n - 1f32)) > tolerance);
+
+                for (i, quadratic) in quadratics.iter().enumerate() {
+                    for j in 0..=100 {
+                        let u = j as f32 / 100f32;
+                        let t = (i as f32 + u) / n;
+                        assert!((curve.evaluate(t) - quadratic.evaluate(u)).length() <= tolerance * 1.001f32);
+                    }
+                }
+
+                // the pieces meet with matching positions and derivatives
+                for pair in quadratics.windows(2) {
+                    let (end, start) = (pair[0].evaluate(1f32), pair[1].evaluate(0f32));
+                    assert_abs_diff_eq!(end.x, start.x, epsilon = 0.00001f32);
+                    assert_abs_diff_eq!(end.y, start.y, epsilon = 0.00001f32);
+                    let (end, start) = (pair[0].evaluate_first_derivative(1f32), pair[1].evaluate_first_derivative(0f32));
+                    assert_abs_diff_eq!(end.x, start.x, epsilon = 0.0001f32);
+                    assert_abs_diff_eq!(end.y, start.y, epsilon = 0.0001f32);
+                }
+            }
+
+            // a cubic with no cubic term is already a quadratic
+            let curve = CubicCurve::new(1f32, 2f32, 3f32, 0f32);
+            assert_eq!(curve.to_quadratics(0.001f32), vec![QuadraticCurve::new(1f32, 2f32, 3f32)]);
+
+            // tolerances that need too many pieces get as many as allowed
+            let curve = CubicCurve::new(0f32, 0f32, 0f32, 1f32);
+            assert_eq!(curve.to_quadratics(1e-30f32).len(), MAX_PIECES as usize);
+        }
+
+        #[test]
+        fn to_lines() {
+            let curve = CubicCurve::from_bezier(
+                Vector2::new(0f32, 0f32),
+                Vector2::new(1f32, 3f32),
+                Vector2::new(4f32, -2f32),
+                Vector2::new(5f32, 1f32),
+            );
+            for &approximation in &[LinearApproximation::ErrorMinimizing, LinearApproximation::ContinuityPreserving] {
+                let mut previous = 0;
+                for &tolerance in &[0.1f32, 0.01f32, 0.001f32] {
+                    let lines = curve.to_lines(tolerance, approximation).unwrap();
+                    assert!(lines.len() > previous);
+                    previous = lines.len();
+
+                    // every point of the curve is close to a line
+                    for i in 0..=1000 {
+                        let point = curve.evaluate(i as f32 / 1000f32);
+                        let distance = lines.iter()
+                            .map(|line| (line.evaluate(line.nearest_point(point)) - point).length())
+                            .fold(f32::INFINITY, f32::min);
+                        assert!(distance <= tolerance);
+                    }
+
+                    if approximation == LinearApproximation::ContinuityPreserving {
+                        assert_eq!(lines[0].evaluate(0f32), curve.evaluate(0f32));
+                        for pair in lines.windows(2) {
+                            let (end, start) = (pair[0].evaluate(1f32), pair[1].evaluate(0f32));
+                            assert_abs_diff_eq!(end.x, start.x, epsilon = 0.00001f32);
+                            assert_abs_diff_eq!(end.y, start.y, epsilon = 0.00001f32);
+                        }
+                    }
+                }
+            }
+
+            // a cubic with no cubic term is split like a quadratic
+            for &approximation in &[LinearApproximation::ErrorMinimizing, LinearApproximation::ContinuityPreserving] {
+                let quadratic = QuadraticCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32), Vector2::new(3f32, 0f32));
+                assert_eq!(quadratic.to_cubic().to_lines(0.001f32, approximation), quadratic.to_lines(0.001f32, approximation));
+            }
+
+            // tolerances that need too many lines are reported
+            assert!(curve.to_lines(1e-30f32, LinearApproximation::ContinuityPreserving).is_none());
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,333 @@
+use crate::{
+    CubicCurve,
+    InnerProductSpace,
+    Scalar,
+    Spline,
+    VectorSpace,
+};
+use std::ops::{
+    Add,
+    Div,
+    Mul,
+    Sub,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CubicSplineBoundary<T: VectorSpace> {
+    // the second derivative is zero at both ends
+    Natural,
+    // the first derivatives with respect to the knots at the first and last knots
+    Clamped(T, T),
+    // the third derivative is continuous across the second and second-to-last knots
+    NotAKnot,
+    // the spline wraps around, and the last value should be the same as the first
+    Periodic,
+}
+
+// Solves a tridiagonal system where lower[i] and upper[i] are the coefficients of x[i - 1] and x[i + 1] in row i
+fn solve_tridiagonal<S: Scalar, V: Add<Output = V> + Copy + Sub<Output = V> + Mul<S, Output = V> + Div<S, Output = V>>(lower: &[S], diagonal: &[S], upper: &[S], rhs: &[V]) -> Vec<V> {
+    let n = diagonal.len();
+    let mut factors = Vec::with_capacity(n);
+    let mut result = Vec::with_capacity(n);
+
+    factors.push(upper[0] / diagonal[0]);
+    result.push(rhs[0] / diagonal[0]);
+    for i in 1..n {
+        let denominator = diagonal[i] - lower[i] * factors[i - 1];
+        factors.push(upper[i] / denominator);
+        result.push((rhs[i] - result[i - 1] * lower[i]) / denominator);
+    }
+
+    for i in (0..n - 1).rev() {
+        result[i] = result[i] - result[i + 1] * factors[i];
+    }
+    result
+}
+
+// Solves a tridiagonal system that also has the coefficients of x[n - 1] in row 0 in lower[0] and of x[0] in row n - 1 in upper[n - 1]
+fn solve_cyclic<S: Scalar, V: Add<Output = V> + Copy + Sub<Output = V> + Mul<S, Output = V> + Div<S, Output = V>>(lower: &[S], diagonal: &[S], upper: &[S], rhs: &[V]) -> Vec<V> {
+    let n = diagonal.len();
+    let zero = S::from_i32(0);
+
+    if n <= 2 {
+        // the corners land on the same entries as the rest of the band
+        let mut folded_lower = vec![zero; n];
+        let mut folded_diagonal = diagonal.to_vec();
+        let mut folded_upper = vec![zero; n];
+        if n == 1 {
+            folded_diagonal[0] += lower[0] + upper[0];
+        } else {
+            folded_upper[0] = lower[0] + upper[0];
+            folded_lower[1] = lower[1] + upper[1];
+        }
+        return solve_tridiagonal(&folded_lower, &folded_diagonal, &folded_upper, rhs);
+    }
+
+    // Sherman-Morrison: remove the corners with a rank one update and correct the tridiagonal solution
+    let top_right = lower[0];
+    let bottom_left = upper[n - 1];
+    let gamma = -diagonal[0];
+
+    let mut band_lower = lower.to_vec();
+    let mut band_diagonal = diagonal.to_vec();
+    let mut band_upper = upper.to_vec();
+    band_lower[0] = zero;
+    band_upper[n - 1] = zero;
+    band_diagonal[0] -= gamma;
+    band_diagonal[n - 1] -= bottom_left * top_right / gamma;
+
+    let mut u = vec![zero; n];
+    u[0] = gamma;
+    u[n - 1] = bottom_left;
+
+    let y = solve_tridiagonal(&band_lower, &band_diagonal, &band_upper, rhs);
+    let z = solve_tridiagonal(&band_lower, &band_diagonal, &band_upper, &u);
+
+    let denominator = S::from_i32(1) + z[0] + top_right * z[n - 1] / gamma;
+    let numerator = y[0] + y[n - 1] * (top_right / gamma);
+    y.iter().zip(z.iter()).map(|(&y, &z)| y - numerator * (z / denominator)).collect()
+}
+
+impl<T: InnerProductSpace> Spline<CubicCurve<T>> {
+    // Builds a C2 continuous spline through the values that keeps the knots, so evaluating it at knots[i] gives values[i]
+    pub fn interpolate(knots: &[T::Scalar], values: &[T], boundary: CubicSplineBoundary<T>) -> Spline<CubicCurve<T>> {
+        assert_eq!(knots.len(), values.len(), "there must be one value for each knot");
+        assert!(knots.windows(2).all(|pair| pair[0] < pair[1]), "knots must be strictly increasing");
+
+        let n = knots.len();
+        if n < 2 {
+            return Spline::new(Vec::new());
+        }
+
+        let zero = T::Scalar::from_i32(0);
+        let one = T::Scalar::from_i32(1);
+        let two = T::Scalar::from_i32(2);
+        let three = T::Scalar::from_i32(3);
+
+        let h = knots.windows(2).map(|pair| pair[1] - pair[0]).collect::<Vec<_>>();
+        let slopes = values.windows(2).zip(h.iter()).map(|(pair, &h)| (pair[1] - pair[0]) / h).collect::<Vec<_>>();
+
+        // the derivatives with respect to the knots at each knot
+        let derivatives = match boundary {
+            CubicSplineBoundary::Periodic => {
+                let m = n - 1;
+                let previous = |i: usize| (i + m - 1) % m;
+
+                let lower = (0..m).map(|i| h[i]).collect::<Vec<_>>();
+                let diagonal = (0..m).map(|i| (h[previous(i)] + h[i]) * two).collect::<Vec<_>>();
+                let upper = (0..m).map(|i| h[previous(i)]).collect::<Vec<_>>();
+                let rhs = (0..m).map(|i| (slopes[previous(i)] * h[i] + slopes[i] * h[previous(i)]) * three).collect::<Vec<_>>();
+
+                let mut derivatives = solve_cyclic(&lower, &diagonal, &upper, &rhs);
+                derivatives.push(derivatives[0]);
+                derivatives
+            },
+            CubicSplineBoundary::NotAKnot if n == 3 => {
+                // the only interpolant is the parabola through all three values
+                let curvature = (slopes[1] - slopes[0]) / (h[0] + h[1]);
+                vec![
+                    slopes[0] - curvature * h[0],
+                    slopes[0] + curvature * h[0],
+                    slopes[0] + curvature * (h[0] + h[1] * two),
+                ]
+            },
+            _ => {
+                let mut lower = vec![zero; n];
+                let mut diagonal = vec![zero; n];
+                let mut upper = vec![zero; n];
+                let mut rhs = vec![T::zero(); n];
+
+                for i in 1..n - 1 {
+                    lower[i] = h[i];
+                    diagonal[i] = (h[i - 1] + h[i]) * two;
+                    upper[i] = h[i - 1];
+                    rhs[i] = (slopes[i - 1] * h[i] + slopes[i] * h[i - 1]) * three;
+                }
+
+                match boundary {
+                    CubicSplineBoundary::Clamped(start, end) => {
+                        diagonal[0] = one;
+                        rhs[0] = start;
+                        diagonal[n - 1] = one;
+                        rhs[n - 1] = end;
+                    },
+                    CubicSplineBoundary::NotAKnot if n > 3 => {
+                        let width = h[0] + h[1];
+                        diagonal[0] = h[1];
+                        upper[0] = width;
+                        rhs[0] = (slopes[0] * ((h[0] + width * two) * h[1]) + slopes[1] * (h[0] * h[0])) / width;
+
+                        let width = h[n - 3] + h[n - 2];
+                        lower[n - 1] = width;
+                        diagonal[n - 1] = h[n - 3];
+                        rhs[n - 1] = (slopes[n - 3] * (h[n - 2] * h[n - 2]) + slopes[n - 2] * ((h[n - 2] + width * two) * h[n - 3])) / width;
+                    },
+                    // natural, which is also the straight line that not-a-knot gives for two knots
+                    _ => {
+                        diagonal[0] = two;
+                        upper[0] = one;
+                        rhs[0] = slopes[0] * three;
+                        lower[n - 1] = one;
+                        diagonal[n - 1] = two;
+                        rhs[n - 1] = slopes[n - 2] * three;
+                    },
+                }
+
+                solve_tridiagonal(&lower, &diagonal, &upper, &rhs)
+            },
+        };
+
+        let segments = (0..n - 1)
+            .map(|i| CubicCurve::from_hermite(values[i], derivatives[i] * h[i], values[i + 1], derivatives[i + 1] * h[i]))
+            .collect();
+        Spline::with_knots(segments, knots.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod cubic_spline {
+        use crate::{
+            CubicCurve,
+            CubicSplineBoundary,
+            Spline,
+            Vector2,
+        };
+        use approx::assert_abs_diff_eq;
+
+        fn knots() -> [f32; 5] {
+            [0f32, 1f32, 1.5f32, 3f32, 4f32]
+        }
+
+        fn values() -> [f32; 5] {
+            [1f32, 2f32, -1f32, 0.5f32, 1f32]
+        }
+
+        // the derivatives of each segment with respect to the knots instead of its own parameter
+        fn derivatives(spline: &Spline<CubicCurve<f32>>, knots: &[f32], i: usize, u: f32) -> (f32, f32) {
+            let segment = spline.segments()[i];
+            let h = knots[i + 1] - knots[i];
+            (segment.evaluate_first_derivative(u) / h, segment.evaluate_second_derivative(u) / (h * h))
+        }
+
+        fn check_spline(spline: &Spline<CubicCurve<f32>>, knots: &[f32], values: &[f32]) {
+            assert_eq!(spline.len(), knots.len() - 1);
+            assert_eq!(spline.knots(), knots);
+            for (&knot, &value) in knots.iter().zip(values.iter()) {
+                assert_abs_diff_eq!(spline.evaluate(knot).unwrap(), value, epsilon = 0.00001f32);
+            }
+            for i in 1..spline.len() {
+                let (left_first, left_second) = derivatives(spline, knots, i - 1, 1f32);
+                let (right_first, right_second) = derivatives(spline, knots, i, 0f32);
+                assert_abs_diff_eq!(left_first, right_first, epsilon = 0.0001f32);
+                assert_abs_diff_eq!(left_second, right_second, epsilon = 0.0001f32);
+            }
+        }
+
+        #[test]
+        fn natural() {
+            let (knots, values) = (knots(), values());
+            let spline = Spline::interpolate(&knots, &values, CubicSplineBoundary::Natural);
+            check_spline(&spline, &knots, &values);
+            assert_abs_diff_eq!(derivatives(&spline, &knots, 0, 0f32).1, 0f32, epsilon = 0.0001f32);
+            assert_abs_diff_eq!(derivatives(&spline, &knots, 3, 1f32).1, 0f32, epsilon = 0.0001f32);
+
+            // a straight line stays straight
+            let spline = Spline::interpolate(&knots, &[0f32, 2f32, 3f32, 6f32, 8f32], CubicSplineBoundary::Natural);
+            for i in 0..4 {
+                assert_abs_diff_eq!(derivatives(&spline, &knots, i, 0.5f32).0, 2f32, epsilon = 0.0001f32);
+            }
+
+            let spline = Spline::interpolate(&[0f32, 2f32], &[1f32, 5f32], CubicSplineBoundary::Natural);
+            assert_abs_diff_eq!(spline.evaluate(0.5f32).unwrap(), 2f32, epsilon = 0.00001f32);
+            assert_abs_diff_eq!(spline.evaluate_derivative(0.5f32).unwrap(), 2f32, epsilon = 0.00001f32);
+        }
+
+        #[test]
+        fn clamped() {
+            let (knots, values) = (knots(), values());
+            let spline = Spline::interpolate(&knots, &values, CubicSplineBoundary::Clamped(-1f32, 2f32));
+            check_spline(&spline, &knots, &values);
+            assert_abs_diff_eq!(derivatives(&spline, &knots, 0, 0f32).0, -1f32, epsilon = 0.0001f32);
+            assert_abs_diff_eq!(derivatives(&spline, &knots, 3, 1f32).0, 2f32, epsilon = 0.0001f32);
+
+            // a cubic with the right end derivatives is reproduced exactly
+            let f = |x: f32| x * x * x - 2f32 * x * x + 1f32;
+            let df = |x: f32| 3f32 * x * x - 4f32 * x;
+            let values = knots.map(f);
+            let spline = Spline::interpolate(&knots, &values, CubicSplineBoundary::Clamped(df(0f32), df(4f32)));
+            for i in 0..4 {
+                assert_abs_diff_eq!(spline.evaluate_derivative(knots[i]).unwrap(), df(knots[i]), epsilon = 0.0001f32);
+                let x = (knots[i] + knots[i + 1]) / 2f32;
+                assert_abs_diff_eq!(spline.evaluate(x).unwrap(), f(x), epsilon = 0.0001f32);
+            }
+        }
+
+        #[test]
+        fn not_a_knot() {
+            let (knots, values) = (knots(), values());
+            let spline = Spline::interpolate(&knots, &values, CubicSplineBoundary::NotAKnot);
+            check_spline(&spline, &knots, &values);
+            for &i in &[1, 3] {
+                let left = spline.segments()[i - 1].evaluate_third_derivative() / (knots[i] - knots[i - 1]).powi(3);
+                let right = spline.segments()[i].evaluate_third_derivative() / (knots[i + 1] - knots[i]).powi(3);
+                assert_abs_diff_eq!(left, right, epsilon = 0.001f32);
+            }
+
+            // any cubic is reproduced exactly
+            let f = |x: f32| -x * x * x + 3f32 * x * x + x - 2f32;
+            let spline = Spline::interpolate(&knots, &knots.map(f), CubicSplineBoundary::NotAKnot);
+            for i in 0..4 {
+                let x = (knots[i] + knots[i + 1]) / 2f32;
+                assert_abs_diff_eq!(spline.evaluate(x).unwrap(), f(x), epsilon = 0.0001f32);
+            }
+
+            // three knots give the parabola through them
+            let f = |x: f32| 2f32 * x * x - x + 1f32;
+            let knots = [0f32, 0.5f32, 2f32];
+            let spline = Spline::interpolate(&knots, &knots.map(f), CubicSplineBoundary::NotAKnot);
+            assert_abs_diff_eq!(spline.evaluate(0.25f32).unwrap(), f(0.25f32), epsilon = 0.00001f32);
+            assert_abs_diff_eq!(spline.evaluate(1.25f32).unwrap(), f(1.25f32), epsilon = 0.00001f32);
+        }
+
+        #[test]
+        fn periodic() {
+            let knots = knots();
+            let values = [1f32, 2f32, -1f32, 0.5f32, 1f32];
+            let spline = Spline::interpolate(&knots, &values, CubicSplineBoundary::Periodic);
+            check_spline(&spline, &knots, &values);
+
+            let (first, second) = derivatives(&spline, &knots, 0, 0f32);
+            let (last_first, last_second) = derivatives(&spline, &knots, 3, 1f32);
+            assert_abs_diff_eq!(first, last_first, epsilon = 0.0001f32);
+            assert_abs_diff_eq!(second, last_second, epsilon = 0.0001f32);
+
+            // small periods fold the wrap around into the band
+            for count in 2..=3 {
+                let knots = &knots[..count];
+                let mut values = values[..count].to_vec();
+                values[count - 1] = values[0];
+                let spline = Spline::interpolate(knots, &values, CubicSplineBoundary::Periodic);
+                check_spline(&spline, knots, &values);
+                let (first, second) = derivatives(&spline, knots, 0, 0f32);
+                let (last_first, last_second) = derivatives(&spline, knots, count - 2, 1f32);
+                assert_abs_diff_eq!(first, last_first, epsilon = 0.0001f32);
+                assert_abs_diff_eq!(second, last_second, epsilon = 0.0001f32);
+            }
+        }
+
+        #[test]
+        fn vectors() {
+            let knots = [0f32, 1f32, 3f32, 4f32];
+            let points = [Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32), Vector2::new(3f32, 1f32), Vector2::new(4f32, 4f32)];
+            let spline = Spline::interpolate(&knots, &points, CubicSplineBoundary::Natural);
+            let xs = Spline::interpolate(&knots, &points.map(|p| p.x), CubicSplineBoundary::Natural);
+            let ys = Spline::interpolate(&knots, &points.map(|p| p.y), CubicSplineBoundary::Natural);
+            for i in 0..=12 {
+                let t = i as f32 / 4f32;
+                assert_abs_diff_eq!(spline.evaluate(t).unwrap().x, xs.evaluate(t).unwrap(), epsilon = 0.00001f32);
+                assert_abs_diff_eq!(spline.evaluate(t).unwrap().y, ys.evaluate(t).unwrap(), epsilon = 0.00001f32);
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,282 @@
+use crate::{
+    CubicCurve,
+    InnerProductSpace,
+    QuadraticCurve,
+    Scalar,
+    Vector2,
+    Vector3,
+    roots::solve_polynomial,
+};
+
+// The curvature of a curve with the given first and second derivatives, which is undefined where the curve stops
+fn curvature<T: InnerProductSpace>(velocity: T, acceleration: T) -> T::Scalar {
+    // the part of the acceleration that turns the curve instead of changing its speed
+    let speed2 = velocity.inner_product(velocity);
+    let turning = acceleration - velocity * (acceleration.inner_product(velocity) / speed2);
+    turning.inner_product(turning).sqrt() / speed2
+}
+
+// The curvature, which is positive when the curve turns counterclockwise and negative when it turns clockwise
+fn signed_curvature<S: Scalar>(velocity: Vector2<S>, acceleration: Vector2<S>) -> S {
+    let speed = velocity.length();
+    velocity.cross(acceleration) / (speed * speed * speed)
+}
+
+// The inner product of the bivectors p ^ q and r ^ s
+fn wedge_inner_product<T: InnerProductSpace>((p, q): (T, T), (r, s): (T, T)) -> T::Scalar {
+    p.inner_product(r) * q.inner_product(s) - p.inner_product(s) * q.inner_product(r)
+}
+
+// Adds the product of the polynomials to the result, with all coefficients ordered from the lowest degree to the
+// highest
+fn multiply_add<S: Scalar>(a: &[S], b: &[S], scale: S, result: &mut [S]) {
+    for (i, &a) in a.iter().enumerate() {
+        for (j, &b) in b.iter().enumerate() {
+            result[i + j] += a * b * scale;
+        }
+    }
+}
+
+impl<T: InnerProductSpace> QuadraticCurve<T> {
+    pub fn curvature(&self, t: T::Scalar) -> T::Scalar {
+        curvature(self.evaluate_first_derivative(t), self.evaluate_second_derivative())
+    }
+
+    // Infinite where the curve is straight
+    pub fn radius_of_curvature(&self, t: T::Scalar) -> T::Scalar {
+        T::Scalar::from_i32(1) / self.curvature(t)
+    }
+
+    // The parameter where the curve is the most curved, which is the vertex of its parabola if that is inside the
+    // curve and the nearest end otherwise
+    pub fn max_curvature(&self) -> T::Scalar {
+        let zero = T::Scalar::from_i32(0);
+        let one = T::Scalar::from_i32(1);
+
+        let a = self.x2().inner_product(self.x2());
+        if a == zero {
+            return zero;
+        }
+
+        // the derivative is perpendicular to the axis of the parabola at its vertex
+        let t = -self.x1().inner_product(self.x2()) / (a * T::Scalar::from_i32(2));
+        if t < zero {
+            zero
+        } else if t > one {
+            one
+        } else {
+            t
+        }
+    }
+}
+
+impl<T: InnerProductSpace> CubicCurve<T> {
+    pub fn curvature(&self, t: T::Scalar) -> T::Scalar {
+        curvature(self.evaluate_first_derivative(t), self.evaluate_second_derivative(t))
+    }
+
+    // Infinite where the curve is straight
+    pub fn radius_of_curvature(&self, t: T::Scalar) -> T::Scalar {
+        T::Scalar::from_i32(1) / self.curvature(t)
+    }
+
+    // The parameter where the curve is the most curved
+    pub fn max_curvature(&self) -> T::Scalar {
+        let from_i32 = T::Scalar::from_i32;
+
+        // the curvature squared is c(t) / v(t)^3, where c(t) is the squared length of f'(t) ^ f''(t) and v(t) is the
+        // squared length of f'(t)
+        let w = [
+            (self.x1() * from_i32(2), self.x2()),
+            (self.x1() * from_i32(6), self.x3()),
+            (self.x2() * from_i32(6), self.x3()),
+        ];
+        let c = [
+            wedge_inner_product(w[0], w[0]),
+            wedge_inner_product(w[0], w[1]) * from_i32(2),
+            wedge_inner_product(w[0], w[2]) * from_i32(2) + wedge_inner_product(w[1], w[1]),
+            wedge_inner_product(w[1], w[2]) * from_i32(2),
+            wedge_inner_product(w[2], w[2]),
+        ];
+        let d = [self.x1(), self.x2() * from_i32(2), self.x3() * from_i32(3)];
+        let v = [
+            d[0].inner_product(d[0]),
+            d[0].inner_product(d[1]) * from_i32(2),
+            d[0].inner_product(d[2]) * from_i32(2) + d[1].inner_product(d[1]),
+            d[1].inner_product(d[2]) * from_i32(2),
+            d[2].inner_product(d[2]),
+        ];
+
+        // the extrema are where c'(t) v(t) - 3 c(t) v'(t) = 0
+        let c_derivative = [c[1], c[2] * from_i32(2), c[3] * from_i32(3), c[4] * from_i32(4)];
+        let v_derivative = [v[1], v[2] * from_i32(2), v[3] * from_i32(3), v[4] * from_i32(4)];
+        let mut coefficients = [from_i32(0); 8];
+        multiply_add(&c_derivative, &v, from_i32(1), &mut coefficients);
+        multiply_add(&c, &v_derivative, from_i32(-3), &mut coefficients);
+        coefficients.reverse();
+
+        let mut best = from_i32(0);
+        let mut best_curvature = from_i32(-1);
+        let roots = solve_polynomial(&coefficients, (from_i32(0), from_i32(1)));
+        for &t in [from_i32(0)].iter().chain(roots.iter().flatten()).chain([from_i32(1)].iter()) {
+            let velocity = self.evaluate_first_derivative(t);
+            if velocity.inner_product(velocity) == from_i32(0) {
+                // the curve stops here, so it can turn by any amount
+                return t;
+            }
+
+            let curvature = self.curvature(t);
+            if curvature > best_curvature {
+                best = t;
+                best_curvature = curvature;
+            }
+        }
+        best
+    }
+}
+
+impl<S: Scalar> QuadraticCurve<Vector2<S>> {
+    // Positive when the curve turns counterclockwise and negative when it turns clockwise
+    pub fn signed_curvature(&self, t: S) -> S {
+        signed_curvature(self.evaluate_first_derivative(t), self.evaluate_second_derivative())
+    }
+}
+
+impl<S: Scalar> CubicCurve<Vector2<S>> {
+    // Positive when the curve turns counterclockwise and negative when it turns clockwise
+    pub fn signed_curvature(&self, t: S) -> S {
+        signed_curvature(self.evaluate_first_derivative(t), self.evaluate_second_derivative(t))
+    }
+}
+
+impl<S: Scalar> CubicCurve<Vector3<S>> {
+    // How quickly the curve twists out of the plane it is curving in, which is undefined where the curve is straight.
+    // Quadratic curves always lie in a plane, and so have no torsion.
+    pub fn torsion(&self, t: S) -> S {
+        let normal = self.evaluate_first_derivative(t).cross(self.evaluate_second_derivative(t));
+        normal.inner_product(self.evaluate_third_derivative()) / normal.inner_product(normal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod curvature {
+        use crate::{
+            CubicCurve,
+            QuadraticCurve,
+            Vector2,
+            Vector3,
+        };
+        use approx::{
+            assert_abs_diff_eq,
+            assert_ulps_eq,
+        };
+
+        #[test]
+        fn quadratic() {
+            // y = x^2 has a curvature of 2 / (1 + 4x^2)^(3/2)
+            let curve = QuadraticCurve::new(Vector2::new(0f32, 0f32), Vector2::new(1f32, 0f32), Vector2::new(0f32, 1f32));
+            for i in 0..=10 {
+                let t = i as f32 / 10f32;
+                let expected = 2f32 / (1f32 + 4f32 * t * t).powf(1.5f32);
+                assert_ulps_eq!(curve.curvature(t), expected, max_ulps = 8);
+                assert_ulps_eq!(curve.signed_curvature(t), expected, max_ulps = 8);
+                assert_ulps_eq!(curve.radius_of_curvature(t), 1f32 / expected, max_ulps = 8);
+            }
+
+            // turning the other way flips the sign
+            let mirrored = QuadraticCurve::new(Vector2::new(0f32, 0f32), Vector2::new(1f32, 0f32), Vector2::new(0f32, -1f32));
+            assert_ulps_eq!(mirrored.signed_curvature(0.5f32), -curve.signed_curvature(0.5f32));
+            assert_ulps_eq!(mirrored.curvature(0.5f32), curve.curvature(0.5f32));
+
+            // a straight curve never turns
+            let line = QuadraticCurve::new(Vector2::new(0f32, 0f32), Vector2::new(1f32, 1f32), Vector2::new(2f32, 2f32));
+            assert_eq!(line.curvature(0.5f32), 0f32);
+            assert_eq!(line.radius_of_curvature(0.5f32), f32::INFINITY);
+        }
+
+        #[test]
+        fn quadratic_max_curvature() {
+            let curve = QuadraticCurve::new(Vector2::new(0f32, 0f32), Vector2::new(1f32, -0.6f32), Vector2::new(0f32, 1f32));
+            assert_ulps_eq!(curve.max_curvature(), 0.3f32);
+            assert_ulps_eq!(curve.curvature(0.3f32), 2f32);
+
+            // the vertex is outside of the curve, so the end nearest to it is the most curved
+            let curve = QuadraticCurve::new(Vector2::new(0f32, 0f32), Vector2::new(1f32, 1f32), Vector2::new(0f32, 1f32));
+            assert_eq!(curve.max_curvature(), 0f32);
+            let curve = QuadraticCurve::new(Vector2::new(0f32, 0f32), Vector2::new(1f32, -3f32), Vector2::new(0f32, 1f32));
+            assert_eq!(curve.max_curvature(), 1f32);
+
+            let line = QuadraticCurve::new(Vector2::new(0f32, 0f32), Vector2::new(1f32, 1f32), Vector2::new(0f32, 0f32));
+            assert_eq!(line.max_curvature(), 0f32);
+        }
+
+        #[test]
+        fn cubic() {
+            let curve = CubicCurve::from_bezier(
+                Vector2::new(0f32, 0f32),
+                Vector2::new(1f32, 2f32),
+                Vector2::new(3f32, -2f32),
+                Vector2::new(4f32, 0f32),
+            );
+            for i in 0..=10 {
+                let t = i as f32 / 10f32;
+                assert_ulps_eq!(curve.curvature(t), curve.signed_curvature(t).abs(), max_ulps = 8);
+            }
+            // an s-curve turns clockwise and then counterclockwise
+            assert!(curve.signed_curvature(0.1f32) < 0f32);
+            assert!(curve.signed_curvature(0.9f32) > 0f32);
+
+            // (t, t^2, t^3) has a curvature of 2 and a torsion of 3 at t = 0
+            let curve = CubicCurve::new(Vector3::new(0f32, 0f32, 0f32), Vector3::new(1f32, 0f32, 0f32), Vector3::new(0f32, 1f32, 0f32), Vector3::new(0f32, 0f32, 1f32));
+            assert_ulps_eq!(curve.curvature(0f32), 2f32);
+            assert_ulps_eq!(curve.torsion(0f32), 3f32);
+            // in general its torsion is 3 / (9t^4 + 9t^2 + 1)
+            assert_ulps_eq!(curve.torsion(1f32), 3f32 / 19f32);
+
+            let planar = CubicCurve::new(Vector3::new(0f32, 0f32, 0f32), Vector3::new(1f32, 0f32, 0f32), Vector3::new(0f32, 1f32, 0f32), Vector3::new(1f32, -1f32, 0f32));
+            assert_eq!(planar.torsion(0.5f32), 0f32);
+        }
+
+        #[test]
+        fn cubic_max_curvature() {
+            let curves = [
+                CubicCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32), Vector2::new(3f32, -2f32), Vector2::new(4f32, 0f32)),
+                CubicCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(5f32, 2f32), Vector2::new(-1f32, 2f32), Vector2::new(3f32, 0f32)),
+                CubicCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(0f32, 1f32), Vector2::new(1f32, 1f32), Vector2::new(1f32, 0f32)),
+            ];
+            for curve in curves.iter() {
+                let t = curve.max_curvature();
+                let max = curve.curvature(t);
+                for i in 0..=1000 {
+                    assert!(curve.curvature(i as f32 / 1000f32) <= max * 1.0001f32);
+                }
+            }
+
+            let curve = CubicCurve::new(Vector3::new(0f32, 0f32, 0f32), Vector3::new(1f32, 0f32, 0f32), Vector3::new(0f32, 1f32, 0f32), Vector3::new(0f32, 0f32, 1f32));
+            assert_eq!(curve.max_curvature(), 0f32);
+
+            // a cusp is sharper than anything else on the curve
+            let cusp = CubicCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(4f32, 1f32), Vector2::new(0f32, 1f32), Vector2::new(4f32, 0f32));
+            assert_eq!(cusp.max_curvature(), 0.5f32);
+
+            // a tiny loop next to the cusp needs the precision of f64
+            let looped = CubicCurve::from_bezier(Vector2::new(0f64, 0f64), Vector2::new(4f64, 1.2f64), Vector2::new(0f64, 1f64), Vector2::new(4f64, 0f64));
+            let t = looped.max_curvature();
+            assert_abs_diff_eq!(t, 0.47843f64, epsilon = 0.00001f64);
+            for i in 0..=1000 {
+                assert!(looped.curvature(i as f64 / 1000f64) <= looped.curvature(t));
+            }
+
+            // a cusp at a parameter that isn't exactly representable, where the polynomial only touches zero
+            let t0 = 0.3f64;
+            let (p, q) = (Vector2::new(1f64, 0f64), Vector2::new(0f64, 1f64));
+            let cusp = CubicCurve::new(Vector2::new(0f64, 0f64), q * (t0 * t0) - p * t0, (p - q * (2f64 * t0)) / 2f64, q / 3f64);
+            assert_abs_diff_eq!(cusp.max_curvature(), t0, epsilon = 0.00001f64);
+
+            // a quadratic stored as a cubic is most curved at the vertex of its parabola
+            let quadratic = QuadraticCurve::new(Vector2::new(0f32, 0f32), Vector2::new(1f32, -0.6f32), Vector2::new(0f32, 1f32));
+            assert_abs_diff_eq!(quadratic.to_cubic().max_curvature(), 0.3f32, epsilon = 0.0001f32);
+        }
+    }
+}
\ No newline at end of file
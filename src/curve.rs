@@ -0,0 +1,206 @@
+use crate::{
+    CubicCurve,
+    InnerProductSpace,
+    LinearCurve,
+    QuadraticCurve,
+    VectorSpace,
+};
+
+pub trait Curve: Sized {
+    type Vector: InnerProductSpace;
+    type ControlPoints;
+
+    fn degree(&self) -> usize;
+
+    fn evaluate(&self, t: <Self::Vector as VectorSpace>::Scalar) -> Self::Vector;
+
+    fn evaluate_derivative(&self, t: <Self::Vector as VectorSpace>::Scalar) -> Self::Vector;
+
+    fn subdivide(&self, u: <Self::Vector as VectorSpace>::Scalar) -> (Self, Self);
+
+    fn to_bezier(&self) -> Self::ControlPoints;
+
+    // curves with a closed-form length ignore the tolerance
+    fn length(&self, u: <Self::Vector as VectorSpace>::Scalar, tolerance: <Self::Vector as VectorSpace>::Scalar) -> <Self::Vector as VectorSpace>::Scalar;
+
+    // clamps to the ends of the curve, and returns None if the curve has a length of zero
+    fn parameterize(&self, v: <Self::Vector as VectorSpace>::Scalar, tolerance: <Self::Vector as VectorSpace>::Scalar, max_iters: usize) -> Option<<Self::Vector as VectorSpace>::Scalar>;
+
+    fn nearest_point(&self, q: Self::Vector) -> <Self::Vector as VectorSpace>::Scalar;
+}
+
+impl<T: InnerProductSpace> Curve for LinearCurve<T> {
+    type Vector = T;
+    type ControlPoints = (T, T);
+
+    fn degree(&self) -> usize {
+        1
+    }
+
+    fn evaluate(&self, t: T::Scalar) -> T {
+        LinearCurve::evaluate(self, t)
+    }
+
+    fn evaluate_derivative(&self, _: T::Scalar) -> T {
+        LinearCurve::evaluate_derivative(self)
+    }
+
+    fn subdivide(&self, u: T::Scalar) -> (Self, Self) {
+        LinearCurve::subdivide(self, u)
+    }
+
+    fn to_bezier(&self) -> Self::ControlPoints {
+        LinearCurve::to_bezier(self)
+    }
+
+    fn length(&self, u: T::Scalar, _: T::Scalar) -> T::Scalar {
+        LinearCurve::length(self, u)
+    }
+
+    fn parameterize(&self, v: T::Scalar, _: T::Scalar, _: usize) -> Option<T::Scalar> {
+        LinearCurve::parameterize(self, v)
+    }
+
+    fn nearest_point(&self, q: T) -> T::Scalar {
+        LinearCurve::nearest_point(self, q)
+    }
+}
+
+impl<T: InnerProductSpace> Curve for QuadraticCurve<T> {
+    type Vector = T;
+    type ControlPoints = (T, T, T);
+
+    fn degree(&self) -> usize {
+        2
+    }
+
+    fn evaluate(&self, t: T::Scalar) -> T {
+        QuadraticCurve::evaluate(self, t)
+    }
+
+    fn evaluate_derivative(&self, t: T::Scalar) -> T {
+        QuadraticCurve::evaluate_first_derivative(self, t)
+    }
+
+    fn subdivide(&self, u: T::Scalar) -> (Self, Self) {
+        QuadraticCurve::subdivide(self, u)
+    }
+
+    fn to_bezier(&self) -> Self::ControlPoints {
+        QuadraticCurve::to_bezier(self)
+    }
+
+    fn length(&self, u: T::Scalar, _: T::Scalar) -> T::Scalar {
+        QuadraticCurve::length(self, u)
+    }
+
+    fn parameterize(&self, v: T::Scalar, tolerance: T::Scalar, max_iters: usize) -> Option<T::Scalar> {
+        QuadraticCurve::parameterize(self, v, tolerance, max_iters)
+    }
+
+    fn nearest_point(&self, q: T) -> T::Scalar {
+        QuadraticCurve::nearest_point(self, q)
+    }
+}
+
+impl<T: InnerProductSpace> Curve for CubicCurve<T> {
+    type Vector = T;
+    type ControlPoints = (T, T, T, T);
+
+    fn degree(&self) -> usize {
+        3
+    }
+
+    fn evaluate(&self, t: T::Scalar) -> T {
+        CubicCurve::evaluate(self, t)
+    }
+
+    fn evaluate_derivative(&self, t: T::Scalar) -> T {
+        CubicCurve::evaluate_first_derivative(self, t)
+    }
+
+    fn subdivide(&self, u: T::Scalar) -> (Self, Self) {
+        CubicCurve::subdivide(self, u)
+    }
+
+    fn to_bezier(&self) -> Self::ControlPoints {
+        CubicCurve::to_bezier(self)
+    }
+
+    fn length(&self, u: T::Scalar, tolerance: T::Scalar) -> T::Scalar {
+        CubicCurve::length(self, u, tolerance)
+    }
+
+    fn parameterize(&self, v: T::Scalar, tolerance: T::Scalar, max_iters: usize) -> Option<T::Scalar> {
+        CubicCurve::parameterize(self, v, tolerance, max_iters)
+    }
+
+    fn nearest_point(&self, q: T) -> T::Scalar {
+        CubicCurve::nearest_point(self, q).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod curve {
+        use crate::{
+            Curve,
+            CubicCurve,
+            InnerProductSpace,
+            LinearCurve,
+            QuadraticCurve,
+            Vector2,
+        };
+        use approx::{
+            assert_abs_diff_eq,
+            assert_ulps_eq,
+        };
+
+        fn check_curve<T: InnerProductSpace<Scalar = f32>, C: Curve<Vector = T>>(curve: &C, degree: usize) {
+            assert_eq!(curve.degree(), degree);
+
+            let length = curve.length(1f32, 0.0001f32);
+            let (left, right) = curve.subdivide(0.25f32);
+            assert_abs_diff_eq!(left.length(1f32, 0.0001f32) + right.length(1f32, 0.0001f32), length, epsilon = 0.0001f32);
+            for i in 0..=4 {
+                let t = i as f32 / 4f32;
+                let difference = right.evaluate(t) - curve.evaluate(0.25f32 + 0.75f32 * t);
+                assert_abs_diff_eq!(difference.inner_product(difference), 0f32, epsilon = 0.000001f32);
+            }
+
+            // parameters are clamped to the ends of the curve
+            assert_eq!(curve.parameterize(-1f32, 0.0001f32, 16), Some(0f32));
+            assert_eq!(curve.parameterize(length + 1f32, 0.0001f32, 16), Some(1f32));
+            let beyond = curve.evaluate(1f32) + curve.evaluate_derivative(1f32);
+            assert_ulps_eq!(curve.nearest_point(beyond), 1f32);
+            let before = curve.evaluate(0f32) - curve.evaluate_derivative(0f32);
+            assert_ulps_eq!(curve.nearest_point(before), 0f32);
+
+            for i in 0..=10 {
+                let t = i as f32 / 10f32;
+                let parameter = curve.parameterize(curve.length(t, 0.0001f32), 0.0001f32, 16).unwrap();
+                assert_abs_diff_eq!(curve.length(parameter, 0.0001f32), curve.length(t, 0.0001f32), epsilon = 0.0001f32);
+
+                let point = curve.evaluate(t);
+                let nearest = curve.evaluate(curve.nearest_point(point));
+                assert_abs_diff_eq!((nearest - point).inner_product(nearest - point), 0f32, epsilon = 0.0001f32);
+            }
+        }
+
+        #[test]
+        fn implementations() {
+            let linear = LinearCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32));
+            check_curve(&linear, 1);
+            assert_eq!(Curve::to_bezier(&linear), (Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32)));
+            assert_eq!(Curve::evaluate_derivative(&linear, 0.5f32), Vector2::new(1f32, 2f32));
+
+            let quadratic = QuadraticCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32), Vector2::new(2f32, 0f32));
+            check_curve(&quadratic, 2);
+            assert_eq!(Curve::evaluate_derivative(&quadratic, 0.5f32), Vector2::new(2f32, 0f32));
+
+            let cubic = CubicCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32), Vector2::new(2f32, -1f32), Vector2::new(3f32, 1f32));
+            check_curve(&cubic, 3);
+            assert_ulps_eq!(Curve::nearest_point(&cubic, Vector2::new(3f32, 1f32)), 1f32);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,607 @@
+use crate::{
+    CubicCurve,
+    Curve,
+    InnerProductSpace,
+    QuadraticCurve,
+    Scalar,
+    Spline,
+    VectorSpace,
+    polynomial_curve::binomial,
+};
+
+// Returns parameters between 0 and 1 proportional to the distance along the polyline through the points, or spaced
+// uniformly if the points are all the same
+pub fn chord_length_parameters<T: InnerProductSpace>(points: &[T]) -> Vec<T::Scalar> {
+    let zero = T::Scalar::from_i32(0);
+    if points.is_empty() {
+        return Vec::new();
+    }
+
+    let mut parameters = Vec::with_capacity(points.len());
+    let mut total = zero;
+    parameters.push(zero);
+    for pair in points.windows(2) {
+        let offset = pair[1] - pair[0];
+        total += offset.inner_product(offset).sqrt();
+        parameters.push(total);
+    }
+
+    let last = T::Scalar::from_i32(points.len() as i32 - 1);
+    for (i, parameter) in parameters.iter_mut().enumerate() {
+        if total > zero {
+            *parameter /= total;
+        } else if points.len() > 1 {
+            *parameter = T::Scalar::from_i32(i as i32) / last;
+        }
+    }
+    parameters
+}
+
+// Returns the parameter of the point on the curve nearest to each point, to improve the parameters of a fit
+pub fn reparameterize<C: Curve>(curve: &C, points: &[C::Vector]) -> Vec<<C::Vector as VectorSpace>::Scalar> {
+    points.iter().map(|&point| curve.nearest_point(point)).collect()
+}
+
+// Returns the largest distance between a point and the curve at its parameter, and the index of that point
+pub fn max_error<C: Curve>(curve: &C, points: &[C::Vector], parameters: &[<C::Vector as VectorSpace>::Scalar]) -> (<C::Vector as VectorSpace>::Scalar, usize) {
+    let mut max = <C::Vector as VectorSpace>::Scalar::from_i32(0);
+    let mut index = 0;
+    for (i, (&point, &parameter)) in points.iter().zip(parameters.iter()).enumerate() {
+        let offset = curve.evaluate(parameter) - point;
+        let distance = offset.inner_product(offset);
+        if distance > max {
+            max = distance;
+            index = i;
+        }
+    }
+    (max.sqrt(), index)
+}
+
+// Scales to a length of one, leaving the zero vector alone
+fn normalize<T: InnerProductSpace>(v: T) -> T {
+    let length = v.inner_product(v).sqrt();
+    if length > T::Scalar::from_i32(0) {
+        v / length
+    } else {
+        v
+    }
+}
+
+fn distance<T: InnerProductSpace>(a: T, b: T) -> T::Scalar {
+    (b - a).inner_product(b - a).sqrt()
+}
+
+fn bernstein<S: Scalar>(degree: usize, i: usize, t: S) -> S {
+    let w = S::from_i32(1) - t;
+    let mut result = binomial::<S>(degree, i);
+    for _ in 0..i {
+        result *= t;
+    }
+    for _ in i..degree {
+        result *= w;
+    }
+    result
+}
+
+// Solves a small dense system with scalar coefficients by Gaussian elimination with partial pivoting
+fn solve_dense<T: VectorSpace>(mut matrix: Vec<Vec<T::Scalar>>, mut rhs: Vec<T>) -> Option<Vec<T>> {
+    let n = rhs.len();
+    let zero = T::Scalar::from_i32(0);
+
+    for column in 0..n {
+        let mut pivot = column;
+        for row in column + 1..n {
+            if matrix[row][column].abs() > matrix[pivot][column].abs() {
+                pivot = row;
+            }
+        }
+        if matrix[pivot][column] == zero {
+            return None;
+        }
+        matrix.swap(column, pivot);
+        rhs.swap(column, pivot);
+
+        for row in column + 1..n {
+            let factor = matrix[row][column] / matrix[column][column];
+            let pivot_row = matrix[column].clone();
+            for (value, &pivot_value) in matrix[row].iter_mut().zip(pivot_row.iter()).skip(column) {
+                *value -= factor * pivot_value;
+            }
+            let value = rhs[column];
+            rhs[row] -= value * factor;
+        }
+    }
+
+    for row in (0..n).rev() {
+        let mut value = rhs[row];
+        for k in row + 1..n {
+            value -= rhs[k] * matrix[row][k];
+        }
+        rhs[row] = value / matrix[row][row];
+    }
+    Some(rhs)
+}
+
+// Fits Bezier control points of the given degree, keeping the first and last control points if they are given
+fn fit_bezier<T: InnerProductSpace>(points: &[T], parameters: &[T::Scalar], degree: usize, start: Option<T>, end: Option<T>) -> Option<Vec<T>> {
+    assert_eq!(points.len(), parameters.len(), "there must be one parameter for each point");
+
+    let first = if start.is_some() { 1 } else { 0 };
+    let last = if end.is_some() { degree - 1 } else { degree };
+    let unknowns = (first..=last).collect::<Vec<_>>();
+
+    // each unknown control point needs a distinct parameter to pin it down, which NaN parameters can't do
+    let mut distinct = parameters.iter().copied().filter(|parameter| parameter.partial_cmp(parameter).is_some()).collect::<Vec<_>>();
+    distinct.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    distinct.dedup();
+    if distinct.len() < unknowns.len() {
+        return None;
+    }
+
+    let zero = T::Scalar::from_i32(0);
+    let mut matrix = vec![vec![zero; unknowns.len()]; unknowns.len()];
+    let mut rhs = vec![T::zero(); unknowns.len()];
+    for (&point, &t) in points.iter().zip(parameters.iter()) {
+        // move the fixed control points to the other side of the equations
+        let mut target = point;
+        if let Some(start) = start {
+            target -= start * bernstein(degree, 0, t);
+        }
+        if let Some(end) = end {
+            target -= end * bernstein(degree, degree, t);
+        }
+
+        for (row, &i) in unknowns.iter().enumerate() {
+            let basis = bernstein(degree, i, t);
+            for (column, &j) in unknowns.iter().enumerate() {
+                matrix[row][column] += basis * bernstein(degree, j, t);
+            }
+            rhs[row] += target * basis;
+        }
+    }
+
+    let solution = solve_dense(matrix, rhs)?;
+    let mut control_points = Vec::with_capacity(degree + 1);
+    control_points.extend(start);
+    control_points.extend(solution);
+    control_points.extend(end);
+    Some(control_points)
+}
+
+impl<T: InnerProductSpace> QuadraticCurve<T> {
+    // Returns the quadratic curve that minimizes the sum of squared distances to the points at their parameters, or
+    // None if there are too few distinct parameters to determine it
+    pub fn fit(points: &[T], parameters: &[T::Scalar]) -> Option<QuadraticCurve<T>> {
+        let b = fit_bezier(points, parameters, 2, None, None)?;
+        Some(QuadraticCurve::from_bezier(b[0], b[1], b[2]))
+    }
+
+    // Like QuadraticCurve::fit, but the curve starts at start and ends at end
+    pub fn fit_with_endpoints(points: &[T], parameters: &[T::Scalar], start: T, end: T) -> Option<QuadraticCurve<T>> {
+        let b = fit_bezier(points, parameters, 2, Some(start), Some(end))?;
+        Some(QuadraticCurve::from_bezier(b[0], b[1], b[2]))
+    }
+
+    // Like QuadraticCurve::fit_with_endpoints, but the derivative at the start points along start_tangent, placing the
+    // control point half the chord length along it if the fit would reverse it or can't determine it
+    pub fn fit_with_start_tangent(points: &[T], parameters: &[T::Scalar], start: T, start_tangent: T, end: T) -> QuadraticCurve<T> {
+        assert_eq!(points.len(), parameters.len(), "there must be one parameter for each point");
+
+        let zero = T::Scalar::from_i32(0);
+        let start_tangent = normalize(start_tangent);
+
+        // b1 = start + a * start_tangent, which is linear in a
+        let (mut c, mut x) = (zero, zero);
+        for (&point, &t) in points.iter().zip(parameters.iter()) {
+            let a = start_tangent * bernstein(2, 1, t);
+            let target = point - start * (bernstein(2, 0, t) + bernstein(2, 1, t)) - end * bernstein(2, 2, t);
+            c += a.inner_product(a);
+            x += target.inner_product(a);
+        }
+
+        let chord_length = distance(start, end);
+        let epsilon = chord_length / T::Scalar::from_i32(1_000_000);
+
+        let mut alpha = zero;
+        if c != zero {
+            alpha = x / c;
+        }
+        if alpha <= epsilon {
+            alpha = chord_length / T::Scalar::from_i32(2);
+        }
+
+        QuadraticCurve::from_bezier(start, start + start_tangent * alpha, end)
+    }
+}
+
+impl<T: InnerProductSpace> CubicCurve<T> {
+    // Returns the cubic curve that minimizes the sum of squared distances to the points at their parameters, or None
+    // if there are too few distinct parameters to determine it
+    pub fn fit(points: &[T], parameters: &[T::Scalar]) -> Option<CubicCurve<T>> {
+        let b = fit_bezier(points, parameters, 3, None, None)?;
+        Some(CubicCurve::from_bezier(b[0], b[1], b[2], b[3]))
+    }
+
+    // Like CubicCurve::fit, but the curve starts at start and ends at end
+    pub fn fit_with_endpoints(points: &[T], parameters: &[T::Scalar], start: T, end: T) -> Option<CubicCurve<T>> {
+        let b = fit_bezier(points, parameters, 3, Some(start), Some(end))?;
+        Some(CubicCurve::from_bezier(b[0], b[1], b[2], b[3]))
+    }
+
+    // Like CubicCurve::fit_with_endpoints, but the derivatives at the ends point along start_tangent and end_tangent,
+    // placing both control points a third of the chord length along them if the fit would reverse either or can't
+    // determine them, as in Schneider's algorithm
+    pub fn fit_with_tangents(points: &[T], parameters: &[T::Scalar], start: T, start_tangent: T, end: T, end_tangent: T) -> CubicCurve<T> {
+        assert_eq!(points.len(), parameters.len(), "there must be one parameter for each point");
+
+        let zero = T::Scalar::from_i32(0);
+        let start_tangent = normalize(start_tangent);
+        let end_tangent = normalize(end_tangent);
+
+        // b1 = start + a1 * start_tangent and b2 = end - a2 * end_tangent, which is linear in a1 and a2
+        let (mut c11, mut c12, mut c22, mut x1, mut x2) = (zero, zero, zero, zero, zero);
+        for (&point, &t) in points.iter().zip(parameters.iter()) {
+            let a1 = start_tangent * bernstein(3, 1, t);
+            let a2 = end_tangent * -bernstein(3, 2, t);
+            let target = point
+                - start * (bernstein(3, 0, t) + bernstein(3, 1, t))
+                - end * (bernstein(3, 2, t) + bernstein(3, 3, t));
+            c11 += a1.inner_product(a1);
+            c12 += a1.inner_product(a2);
+            c22 += a2.inner_product(a2);
+            x1 += target.inner_product(a1);
+            x2 += target.inner_product(a2);
+        }
+
+        let chord_length = distance(start, end);
+        let epsilon = chord_length / T::Scalar::from_i32(1_000_000);
+
+        let determinant = c11 * c22 - c12 * c12;
+        let (mut alpha1, mut alpha2) = (zero, zero);
+        if determinant != zero {
+            alpha1 = (x1 * c22 - c12 * x2) / determinant;
+            alpha2 = (c11 * x2 - c12 * x1) / determinant;
+        }
+        if alpha1 <= epsilon || alpha2 <= epsilon {
+            alpha1 = chord_length / T::Scalar::from_i32(3);
+            alpha2 = alpha1;
+        }
+
+        CubicCurve::from_bezier(start, start + start_tangent * alpha1, end - end_tangent * alpha2, end)
+    }
+}
+
+// Drops points that repeat the point before them, which have no direction to fit
+fn deduplicate<T: InnerProductSpace>(points: &[T]) -> Vec<T> {
+    let mut result: Vec<T> = Vec::with_capacity(points.len());
+    for &point in points.iter() {
+        let distinct = match result.last() {
+            Some(&last) => distance(last, point) > T::Scalar::from_i32(0),
+            None => true,
+        };
+        if distinct {
+            result.push(point);
+        }
+    }
+    result
+}
+
+// The direction of the polyline through the interior point at index i
+fn center_tangent<T: InnerProductSpace>(points: &[T], i: usize) -> T {
+    let tangent = normalize(points[i + 1] - points[i - 1]);
+    if tangent.inner_product(tangent) > T::Scalar::from_i32(0) {
+        tangent
+    } else {
+        normalize(points[i] - points[i - 1])
+    }
+}
+
+// Points that are within this multiple of the tolerance are reparameterized before giving up and splitting
+fn reparameterize_threshold<S: Scalar>(tolerance: S) -> S {
+    tolerance * S::from_i32(4)
+}
+
+// Moves the split away from the ends so that both halves are smaller than the whole
+fn clamp_split(split: usize, len: usize) -> usize {
+    split.clamp(1, len - 2)
+}
+
+fn fit_cubic_range<T: InnerProductSpace>(points: &[T], start_tangent: T, end_tangent: T, tolerance: T::Scalar, max_iters: usize, segments: &mut Vec<CubicCurve<T>>) {
+    let n = points.len();
+    let (start, end) = (points[0], points[n - 1]);
+    if n == 2 {
+        let third = distance(start, end) / T::Scalar::from_i32(3);
+        segments.push(CubicCurve::from_bezier(start, start + start_tangent * third, end - end_tangent * third, end));
+        return;
+    }
+
+    let mut parameters = chord_length_parameters(points);
+    let mut curve = CubicCurve::fit_with_tangents(points, &parameters, start, start_tangent, end, end_tangent);
+    let (mut error, mut split) = max_error(&curve, points, &parameters);
+    if error > tolerance && error <= reparameterize_threshold(tolerance) {
+        for _ in 0..max_iters {
+            parameters = reparameterize(&curve, points);
+            curve = CubicCurve::fit_with_tangents(points, &parameters, start, start_tangent, end, end_tangent);
+            let result = max_error(&curve, points, &parameters);
+            error = result.0;
+            split = result.1;
+            if error <= tolerance {
+                break;
+            }
+        }
+    }
+
+    if error <= tolerance {
+        segments.push(curve);
+    } else {
+        let split = clamp_split(split, n);
+        let center = center_tangent(points, split);
+        fit_cubic_range(&points[..=split], start_tangent, center, tolerance, max_iters, segments);
+        fit_cubic_range(&points[split..], center, end_tangent, tolerance, max_iters, segments);
+    }
+}
+
+// Returns the direction of the end of the last segment, which the next segment starts with
+fn fit_quadratic_range<T: InnerProductSpace>(points: &[T], start_tangent: T, tolerance: T::Scalar, max_iters: usize, segments: &mut Vec<QuadraticCurve<T>>) -> T {
+    let n = points.len();
+    let (start, end) = (points[0], points[n - 1]);
+    if n == 2 {
+        let curve = QuadraticCurve::fit_with_start_tangent(&[], &[], start, start_tangent, end);
+        segments.push(curve);
+        return normalize(curve.evaluate_first_derivative(T::Scalar::from_i32(1)));
+    }
+
+    let mut parameters = chord_length_parameters(points);
+    let mut curve = QuadraticCurve::fit_with_start_tangent(points, &parameters, start, start_tangent, end);
+    let (mut error, mut split) = max_error(&curve, points, &parameters);
+    if error > tolerance && error <= reparameterize_threshold(tolerance) {
+        for _ in 0..max_iters {
+            parameters = reparameterize(&curve, points);
+            curve = QuadraticCurve::fit_with_start_tangent(points, &parameters, start, start_tangent, end);
+            let result = max_error(&curve, points, &parameters);
+            error = result.0;
+            split = result.1;
+            if error <= tolerance {
+                break;
+            }
+        }
+    }
+
+    if error <= tolerance {
+        segments.push(curve);
+        normalize(curve.evaluate_first_derivative(T::Scalar::from_i32(1)))
+    } else {
+        // the end of the left half decides the start of the right half
+        let split = clamp_split(split, n);
+        let center = fit_quadratic_range(&points[..=split], start_tangent, tolerance, max_iters, segments);
+        fit_quadratic_range(&points[split..], center, tolerance, max_iters, segments)
+    }
+}
+
+impl<T: InnerProductSpace> Spline<CubicCurve<T>> {
+    // Schneider's algorithm, which fits cubic curves to the points and splits at the point of maximum error until every
+    // point is within the tolerance, reparameterizing up to max_iters times before each split. The segments meet with
+    // continuous tangent directions
+    pub fn fit(points: &[T], tolerance: T::Scalar, max_iters: usize) -> Spline<CubicCurve<T>> {
+        let points = deduplicate(points);
+        let n = points.len();
+        let mut segments = Vec::new();
+        if n >= 2 {
+            let start_tangent = normalize(points[1] - points[0]);
+            let end_tangent = normalize(points[n - 1] - points[n - 2]);
+            fit_cubic_range(&points, start_tangent, end_tangent, tolerance, max_iters, &mut segments);
+        }
+        Spline::new(segments)
+    }
+}
+
+impl<T: InnerProductSpace> Spline<QuadraticCurve<T>> {
+    // Fits quadratic curves to the points from first to last, splitting at the point of maximum error until every point
+    // is within the tolerance. Each segment starts in the direction the one before it ended
+    pub fn fit(points: &[T], tolerance: T::Scalar, max_iters: usize) -> Spline<QuadraticCurve<T>> {
+        let points = deduplicate(points);
+        let mut segments = Vec::new();
+        if points.len() >= 2 {
+            let start_tangent = normalize(points[1] - points[0]);
+            fit_quadratic_range(&points, start_tangent, tolerance, max_iters, &mut segments);
+        }
+        Spline::new(segments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod fitting {
+        use crate::{
+            Curve,
+            CubicCurve,
+            QuadraticCurve,
+            Spline,
+            Vector2,
+            fitting::{
+                chord_length_parameters,
+                max_error,
+                reparameterize,
+            },
+        };
+        use approx::{
+            assert_abs_diff_eq,
+            assert_ulps_eq,
+        };
+
+        fn assert_close(a: Vector2<f32>, b: Vector2<f32>, epsilon: f32) {
+            assert_abs_diff_eq!(a.x, b.x, epsilon = epsilon);
+            assert_abs_diff_eq!(a.y, b.y, epsilon = epsilon);
+        }
+
+        fn samples(curve: &CubicCurve<Vector2<f32>>, count: usize) -> (Vec<Vector2<f32>>, Vec<f32>) {
+            let parameters = (0..count).map(|i| i as f32 / (count - 1) as f32).collect::<Vec<_>>();
+            (parameters.iter().map(|&t| curve.evaluate(t)).collect(), parameters)
+        }
+
+        // a deterministic wobble standing in for noise
+        fn noise(i: usize) -> Vector2<f32> {
+            Vector2::new(((i * 7919) % 13) as f32 / 13f32 - 0.5f32, ((i * 104729) % 17) as f32 / 17f32 - 0.5f32) * 0.02f32
+        }
+
+        #[test]
+        fn chord_length() {
+            let points = [Vector2::new(0f32, 0f32), Vector2::new(3f32, 0f32), Vector2::new(3f32, 1f32)];
+            assert_eq!(chord_length_parameters(&points), vec![0f32, 0.75f32, 1f32]);
+            assert_eq!(chord_length_parameters(&[1f32, 1f32, 1f32]), vec![0f32, 0.5f32, 1f32]);
+            assert_eq!(chord_length_parameters(&[1f32]), vec![0f32]);
+        }
+
+        #[test]
+        fn quadratic() {
+            let curve = QuadraticCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32), Vector2::new(3f32, 0f32));
+            let parameters = (0..10).map(|i| i as f32 / 9f32).collect::<Vec<_>>();
+            let points = parameters.iter().map(|&t| curve.evaluate(t)).collect::<Vec<_>>();
+
+            let fit = QuadraticCurve::fit(&points, &parameters).unwrap();
+            assert_close(fit.x0(), curve.x0(), 0.0001f32);
+            assert_close(fit.x1(), curve.x1(), 0.0001f32);
+            assert_close(fit.x2(), curve.x2(), 0.0001f32);
+
+            let noisy = points.iter().enumerate().map(|(i, &point)| point + noise(i)).collect::<Vec<_>>();
+            let fit = QuadraticCurve::fit_with_endpoints(&noisy, &parameters, Vector2::new(0f32, 0f32), Vector2::new(3f32, 0f32)).unwrap();
+            assert_eq!(fit.evaluate(0f32), Vector2::new(0f32, 0f32));
+            assert_close(fit.evaluate(1f32), Vector2::new(3f32, 0f32), 0.00001f32);
+            assert!(max_error(&fit, &noisy, &parameters).0 < 0.03f32);
+
+            assert_eq!(QuadraticCurve::fit(&points[..2], &parameters[..2]), None);
+
+            // NaN parameters don't count toward the distinct parameters needed
+            assert_eq!(QuadraticCurve::fit(&points[..3], &[0f32, f32::NAN, 1f32]), None);
+        }
+
+        #[test]
+        fn cubic() {
+            let curve = CubicCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32), Vector2::new(3f32, 3f32), Vector2::new(4f32, 0f32));
+            let (points, parameters) = samples(&curve, 12);
+
+            let fit = CubicCurve::fit(&points, &parameters).unwrap();
+            assert_close(fit.x0(), curve.x0(), 0.001f32);
+            assert_close(fit.x1(), curve.x1(), 0.001f32);
+            assert_close(fit.x2(), curve.x2(), 0.001f32);
+            assert_close(fit.x3(), curve.x3(), 0.001f32);
+
+            let fit = CubicCurve::fit_with_endpoints(&points, &parameters, points[0], points[11]).unwrap();
+            assert_close(fit.x1(), curve.x1(), 0.001f32);
+            assert_close(fit.x2(), curve.x2(), 0.001f32);
+
+            let fit = CubicCurve::fit_with_tangents(&points, &parameters, points[0], Vector2::new(1f32, 2f32), points[11], Vector2::new(1f32, -3f32));
+            let (_, b1, b2, _) = fit.to_bezier();
+            assert_close(b1, Vector2::new(1f32, 2f32), 0.001f32);
+            assert_close(b2, Vector2::new(3f32, 3f32), 0.001f32);
+
+            assert_eq!(CubicCurve::fit(&points[..3], &parameters[..3]), None);
+        }
+
+        #[test]
+        fn tangents() {
+            // samples on a straight line can't determine the tangent magnitudes, so they fall back to a third of the chord
+            let points = [Vector2::new(0f32, 0f32), Vector2::new(1f32, 1f32), Vector2::new(2f32, 2f32), Vector2::new(3f32, 3f32)];
+            let parameters = chord_length_parameters(&points);
+            let fit = CubicCurve::fit_with_tangents(&points, &parameters, points[0], Vector2::new(-1f32, 0f32), points[3], Vector2::new(0f32, -1f32));
+            let (b0, b1, b2, b3) = fit.to_bezier();
+            let third = (18f32).sqrt() / 3f32;
+            assert_eq!(b0, points[0]);
+            assert_close(b1, Vector2::new(-third, 0f32), 0.00001f32);
+            assert_close(b2, Vector2::new(3f32, 3f32 + third), 0.00001f32);
+            assert_close(b3, points[3], 0.00001f32);
+
+            // the fitted tangents always point along the given directions
+            let noisy = points.iter().enumerate().map(|(i, &point)| point + Vector2::new(0f32, (i % 2) as f32)).collect::<Vec<_>>();
+            let fit = CubicCurve::fit_with_tangents(&noisy, &parameters, noisy[0], Vector2::new(1f32, 1f32), noisy[3], Vector2::new(1f32, 0f32));
+            let start = fit.evaluate_first_derivative(0f32);
+            let end = fit.evaluate_first_derivative(1f32);
+            assert_ulps_eq!(start.x, start.y);
+            assert!(start.x > 0f32);
+            assert_abs_diff_eq!(end.y, 0f32, epsilon = 0.00001f32);
+            assert!(end.x > 0f32);
+        }
+
+        #[test]
+        fn iterate() {
+            let curve = CubicCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(0f32, 3f32), Vector2::new(4f32, 3f32), Vector2::new(4f32, 0f32));
+            let (points, _) = samples(&curve, 20);
+            let points = points.iter().enumerate().map(|(i, &point)| point + noise(i)).collect::<Vec<_>>();
+
+            let mut parameters = chord_length_parameters(&points);
+            let mut fit = CubicCurve::fit(&points, &parameters).unwrap();
+            let (initial, _) = max_error(&fit, &points, &parameters);
+            for _ in 0..10 {
+                parameters = reparameterize(&fit, &points);
+                fit = CubicCurve::fit(&points, &parameters).unwrap();
+            }
+            let (error, _) = max_error(&fit, &points, &parameters);
+            assert!(error < initial);
+            assert!(error < 0.03f32);
+        }
+
+        fn stroke() -> Vec<Vector2<f32>> {
+            (0..=60)
+                .map(|i| {
+                    let t = i as f32 / 60f32 * 6f32;
+                    Vector2::new(t, t.sin() * 2f32 + (t * 3f32).cos() * 0.5f32)
+                })
+                .collect()
+        }
+
+        fn check_spline<C: Curve<Vector = Vector2<f32>>>(spline: &Spline<C>, points: &[Vector2<f32>], tolerance: f32) {
+            assert_eq!(spline.evaluate(0f32).unwrap(), points[0]);
+            assert_close(spline.evaluate(spline.len() as f32).unwrap(), points[points.len() - 1], 0.00001f32);
+
+            for &point in points.iter() {
+                let nearest = spline.evaluate(spline.nearest_point(point).unwrap()).unwrap();
+                assert!((nearest - point).length() <= tolerance);
+            }
+
+            for pair in spline.segments().windows(2) {
+                assert_close(pair[0].evaluate(1f32), pair[1].evaluate(0f32), 0.00001f32);
+                let left = pair[0].evaluate_derivative(1f32).normalize();
+                let right = pair[1].evaluate_derivative(0f32).normalize();
+                assert_abs_diff_eq!(left.x, right.x, epsilon = 0.0001f32);
+                assert_abs_diff_eq!(left.y, right.y, epsilon = 0.0001f32);
+            }
+        }
+
+        #[test]
+        fn adaptive_cubics() {
+            let points = stroke();
+            let mut previous = 0;
+            for &tolerance in &[0.1f32, 0.01f32, 0.001f32] {
+                let spline = Spline::<CubicCurve<_>>::fit(&points, tolerance, 4);
+                check_spline(&spline, &points, tolerance);
+                assert!(spline.len() >= previous);
+                previous = spline.len();
+            }
+
+            // collinear points need only one segment
+            let line = (0..10).map(|i| Vector2::new(i as f32, 2f32 * i as f32)).collect::<Vec<_>>();
+            assert_eq!(Spline::<CubicCurve<_>>::fit(&line, 0.001f32, 4).len(), 1);
+
+            // repeated points are ignored
+            let repeated = [Vector2::new(0f32, 0f32), Vector2::new(0f32, 0f32), Vector2::new(1f32, 1f32), Vector2::new(2f32, 0f32), Vector2::new(2f32, 0f32)];
+            check_spline(&Spline::<CubicCurve<_>>::fit(&repeated, 0.001f32, 4), &repeated, 0.001f32);
+
+            assert!(Spline::<CubicCurve<_>>::fit(&points[..1], 0.001f32, 4).is_empty());
+        }
+
+        #[test]
+        fn adaptive_quadratics() {
+            let points = stroke();
+            let mut previous = 0;
+            for &tolerance in &[0.1f32, 0.01f32, 0.001f32] {
+                let spline = Spline::<QuadraticCurve<_>>::fit(&points, tolerance, 4);
+                check_spline(&spline, &points, tolerance);
+                assert!(spline.len() >= previous);
+                previous = spline.len();
+            }
+
+            let line = (0..10).map(|i| Vector2::new(i as f32, 2f32 * i as f32)).collect::<Vec<_>>();
+            assert_eq!(Spline::<QuadraticCurve<_>>::fit(&line, 0.001f32, 4).len(), 1);
+        }
+    }
+}
\ No newline at end of file
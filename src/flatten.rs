@@ -0,0 +1,312 @@
+use crate::{
+    CubicCurve,
+    InnerProductSpace,
+    QuadraticCurve,
+    Scalar,
+    cubic_curve::{
+        MAX_PIECES,
+        piece,
+        piece_count,
+        quadratic_count,
+    },
+};
+
+// An approximation of the integral of (1 + 4x^2)^(-1/4), which grows with the number of points needed to flatten the
+// parabola y = x^2 up to x
+fn approximate_parabola_integral<T: Scalar>(x: T) -> T {
+    let d = T::from_i32(67) / T::from_i32(100);
+    x / (T::from_i32(1) - d + (d * d * d * d + x * x / T::from_i32(4)).sqrt().sqrt())
+}
+
+// An approximation of the inverse of the parabola integral
+fn approximate_inverse_parabola_integral<T: Scalar>(x: T) -> T {
+    let b = T::from_i32(39) / T::from_i32(100);
+    x * (T::from_i32(1) - b + (b * b + x * x / T::from_i32(4)).sqrt())
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Spacing<S> {
+    // the curve is a scaled part of the parabola y = x^2 from x0 to x2, and points are spaced evenly along the
+    // integral from a0 to a2
+    Parabola {
+        a0: S,
+        a2: S,
+        u0: S,
+        u2: S,
+    },
+    // the curve lies on a line and only needs a point where it turns around
+    Line(S),
+}
+
+// The points of a polyline approximately within a tolerance of a quadratic curve, from its start to its end
+#[derive(Clone, Copy, Debug)]
+pub struct QuadraticFlattening<T: InnerProductSpace> {
+    curve: QuadraticCurve<T>,
+    spacing: Spacing<T::Scalar>,
+    count: i32,
+    index: i32,
+}
+
+impl<T: InnerProductSpace> QuadraticFlattening<T> {
+    fn new(curve: QuadraticCurve<T>, tolerance: T::Scalar) -> QuadraticFlattening<T> {
+        assert!(tolerance > T::Scalar::from_i32(0), "the tolerance must be positive");
+
+        let zero = T::Scalar::from_i32(0);
+        let (b0, b1, b2) = curve.to_bezier();
+        let dd = b1 * T::Scalar::from_i32(2) - b0 - b2;
+        let dd2 = dd.inner_product(dd);
+        if dd2 == zero {
+            return QuadraticFlattening {
+                curve,
+                spacing: Spacing::Line(zero),
+                count: 1,
+                index: 0,
+            };
+        }
+
+        // the component of the chord perpendicular to the axis of the parabola
+        let chord = b2 - b0;
+        let rejection = chord - dd * (chord.inner_product(dd) / dd2);
+        let cross = rejection.inner_product(rejection).sqrt() * dd2.sqrt();
+        let u0 = (b1 - b0).inner_product(dd);
+        if cross == zero {
+            let turn = u0 / dd2;
+            let count = if zero < turn && turn < T::Scalar::from_i32(1) { 2 } else { 1 };
+            return QuadraticFlattening {
+                curve,
+                spacing: Spacing::Line(turn),
+                count,
+                index: 0,
+            };
+        }
+
+        let x0 = u0 / cross;
+        let x2 = (b2 - b1).inner_product(dd) / cross;
+        let scale = cross / (dd2.sqrt() * (x2 - x0).abs());
+        let a0 = approximate_parabola_integral(x0);
+        let a2 = approximate_parabola_integral(x2);
+
+        let sqrt_tolerance = tolerance.sqrt();
+        let sqrt_scale = scale.sqrt();
+        let da = (a2 - a0).abs();
+        let value = if (x0 < zero) == (x2 < zero) {
+            da * sqrt_scale
+        } else {
+            // the segment contains the vertex of the parabola, where the curvature is greatest
+            sqrt_tolerance * da / approximate_parabola_integral(sqrt_tolerance / sqrt_scale)
+        };
+        let points = value / (T::Scalar::from_i32(2) * sqrt_tolerance);
+        let count = piece_count(points).unwrap_or(MAX_PIECES);
+
+        QuadraticFlattening {
+            curve,
+            spacing: Spacing::Parabola {
+                a0,
+                a2,
+                u0: approximate_inverse_parabola_integral(a0),
+                u2: approximate_inverse_parabola_integral(a2),
+            },
+            count,
+            index: 0,
+        }
+    }
+
+    fn parameter(&self, index: i32) -> T::Scalar {
+        if index == 0 {
+            return T::Scalar::from_i32(0);
+        }
+        if index == self.count {
+            return T::Scalar::from_i32(1);
+        }
+
+        match self.spacing {
+            Spacing::Parabola { a0, a2, u0, u2 } => {
+                let a = a0 + (a2 - a0) * T::Scalar::from_i32(index) / T::Scalar::from_i32(self.count);
+                (approximate_inverse_parabola_integral(a) - u0) / (u2 - u0)
+            },
+            Spacing::Line(turn) => turn,
+        }
+    }
+}
+
+impl<T: InnerProductSpace> Iterator for QuadraticFlattening<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.index > self.count {
+            return None;
+        }
+
+        let point = self.curve.evaluate(self.parameter(self.index));
+        self.index += 1;
+        Some(point)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.count - self.index + 1).max(0) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: InnerProductSpace> ExactSizeIterator for QuadraticFlattening<T> {}
+
+// The points of a polyline approximately within a tolerance of a cubic curve, from its start to its end
+#[derive(Clone, Copy, Debug)]
+pub struct CubicFlattening<T: InnerProductSpace> {
+    curve: CubicCurve<T>,
+    tolerance: T::Scalar,
+    count: i32,
+    index: i32,
+    current: QuadraticFlattening<T>,
+}
+
+impl<T: InnerProductSpace> CubicFlattening<T> {
+    fn new(curve: CubicCurve<T>, tolerance: T::Scalar) -> CubicFlattening<T> {
+        assert!(tolerance > T::Scalar::from_i32(0), "the tolerance must be positive");
+
+        // a tenth of the tolerance goes to approximating the curve with quadratics, and the rest to flattening them
+        let count = quadratic_count(&curve, tolerance / T::Scalar::from_i32(10));
+        let tolerance = tolerance * T::Scalar::from_i32(9) / T::Scalar::from_i32(10);
+        CubicFlattening {
+            curve,
+            tolerance,
+            count,
+            index: 0,
+            current: CubicFlattening::quadratic(&curve, 0, count, tolerance),
+        }
+    }
+
+    fn quadratic(curve: &CubicCurve<T>, index: i32, count: i32, tolerance: T::Scalar) -> QuadraticFlattening<T> {
+        piece(curve, index, count).continuity_preseving_quadratic_approximation().flatten(tolerance)
+    }
+}
+
+impl<T: InnerProductSpace> Iterator for CubicFlattening<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if let Some(point) = self.current.next() {
+            return Some(point);
+        }
+
+        self.index += 1;
+        if self.index >= self.count {
+            return None;
+        }
+
+        // the first point of each quadratic is the last point of the one before it
+        self.current = CubicFlattening::quadratic(&self.curve, self.index, self.count, self.tolerance);
+        self.current.next();
+        self.current.next()
+    }
+}
+
+impl<T: InnerProductSpace> QuadraticCurve<T> {
+    // Returns the points of a polyline that is approximately within the tolerance of the curve, spaced so that the
+    // error of each line is about the same. There are at most MAX_PIECES lines, so a tolerance that needs more is
+    // exceeded.
+    pub fn flatten(&self, tolerance: T::Scalar) -> QuadraticFlattening<T> {
+        QuadraticFlattening::new(*self, tolerance)
+    }
+}
+
+impl<T: InnerProductSpace> CubicCurve<T> {
+    // Returns the points of a polyline that is approximately within the tolerance of the curve, by flattening its
+    // quadratic approximations. There are at most MAX_PIECES quadratics of at most MAX_PIECES lines each, so a
+    // tolerance that needs more is exceeded.
+    pub fn flatten(&self, tolerance: T::Scalar) -> CubicFlattening<T> {
+        CubicFlattening::new(*self, tolerance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod flatten {
+        use crate::{
+            cubic_curve::MAX_PIECES,
+            CubicCurve,
+            InnerProductSpace,
+            QuadraticCurve,
+            Vector2,
+        };
+        use approx::assert_abs_diff_eq;
+
+        fn distance_to_polyline(point: Vector2<f32>, polyline: &[Vector2<f32>]) -> f32 {
+            polyline.windows(2)
+                .map(|line| {
+                    let direction = line[1] - line[0];
+                    let length2 = direction.inner_product(direction);
+                    let u = if length2 > 0f32 { ((point - line[0]).inner_product(direction) / length2).clamp(0f32, 1f32) } else { 0f32 };
+                    (line[0] + direction * u - point).length()
+                })
+                .fold(f32::INFINITY, f32::min)
+        }
+
+        fn check<F: Fn(f32) -> Vector2<f32>>(curve: F, polyline: &[Vector2<f32>], tolerance: f32) {
+            for &(point, t) in &[(polyline[0], 0f32), (polyline[polyline.len() - 1], 1f32)] {
+                assert_abs_diff_eq!(point.x, curve(t).x, epsilon = 0.00001f32);
+                assert_abs_diff_eq!(point.y, curve(t).y, epsilon = 0.00001f32);
+            }
+            for i in 0..=1000 {
+                let point = curve(i as f32 / 1000f32);
+                assert!(distance_to_polyline(point, polyline) <= tolerance, "{:?} is too far from the polyline", point);
+            }
+        }
+
+        #[test]
+        fn quadratic() {
+            let curves = [
+                QuadraticCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32), Vector2::new(2f32, 0f32)),
+                QuadraticCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(10f32, 0.5f32), Vector2::new(0f32, 1f32)),
+                QuadraticCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(3f32, 0f32), Vector2::new(3f32, 1f32)),
+            ];
+            for curve in curves.iter() {
+                for &tolerance in &[0.1f32, 0.01f32, 0.001f32] {
+                    let flattening = curve.flatten(tolerance);
+                    let count = flattening.len();
+                    let polyline = flattening.collect::<Vec<_>>();
+                    assert_eq!(polyline.len(), count);
+                    check(|t| curve.evaluate(t), &polyline, tolerance);
+                }
+            }
+
+            // an ideal flattening of this curve needs about 7.5 lines at a tolerance of 0.01 and 75 at 0.0001
+            let curve = curves[0];
+            assert!(curve.flatten(0.01f32).count() <= 10);
+            assert!(curve.flatten(0.0001f32).count() <= 90);
+
+            // straight lines only need their ends, plus the point where they turn around
+            let line = QuadraticCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 1f32), Vector2::new(2f32, 2f32));
+            assert_eq!(line.flatten(0.001f32).collect::<Vec<_>>(), vec![Vector2::new(0f32, 0f32), Vector2::new(2f32, 2f32)]);
+            let folded = QuadraticCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(2f32, 0f32), Vector2::new(1f32, 0f32));
+            let polyline = folded.flatten(0.001f32).collect::<Vec<_>>();
+            assert_eq!(polyline.len(), 3);
+            assert_abs_diff_eq!(polyline[1].x, 4f32 / 3f32, epsilon = 0.00001f32);
+            check(|t| folded.evaluate(t), &polyline, 0.00001f32);
+
+            // tolerances that need too many lines get as many as allowed
+            assert_eq!(curve.flatten(1e-30f32).len(), MAX_PIECES as usize + 1);
+        }
+
+        #[test]
+        fn cubic() {
+            let curves = [
+                CubicCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 3f32), Vector2::new(4f32, -2f32), Vector2::new(5f32, 1f32)),
+                CubicCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(3f32, 2f32), Vector2::new(0f32, 2f32), Vector2::new(3f32, 0f32)),
+                CubicCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(0f32, 1f32), Vector2::new(1f32, 1f32), Vector2::new(1f32, 0f32)),
+            ];
+            for curve in curves.iter() {
+                for &tolerance in &[0.1f32, 0.01f32, 0.001f32] {
+                    let polyline = curve.flatten(tolerance).collect::<Vec<_>>();
+                    check(|t| curve.evaluate(t), &polyline, tolerance);
+
+                    // consecutive quadratics do not repeat the point they share
+                    assert!(polyline.windows(2).all(|line| line[0] != line[1]));
+                }
+            }
+
+            let mut flattening = curves[0].flatten(1e-30f32);
+            assert_eq!(flattening.next(), Some(curves[0].evaluate(0f32)));
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,205 @@
+use crate::{
+    CatmullRomBoundary,
+    CubicCurve,
+    InnerProductSpace,
+    Scalar,
+    Spline,
+    catmull_rom::boundary_point,
+};
+
+// A point with the tension, continuity, and bias of the spline passing through it, which are all zero for a uniform
+// Catmull-Rom spline
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TcbKey<T: InnerProductSpace> {
+    point: T,
+    tension: T::Scalar,
+    continuity: T::Scalar,
+    bias: T::Scalar,
+}
+
+impl<T: InnerProductSpace> TcbKey<T> {
+    pub fn new(point: T, tension: T::Scalar, continuity: T::Scalar, bias: T::Scalar) -> TcbKey<T> {
+        TcbKey {
+            point,
+            tension,
+            continuity,
+            bias,
+        }
+    }
+
+    pub fn point(&self) -> T {
+        self.point
+    }
+
+    pub fn tension(&self) -> T::Scalar {
+        self.tension
+    }
+
+    pub fn continuity(&self) -> T::Scalar {
+        self.continuity
+    }
+
+    pub fn bias(&self) -> T::Scalar {
+        self.bias
+    }
+
+    // Returns the derivatives arriving at and leaving the key, given the points before and after it
+    fn tangents(&self, previous: T, next: T) -> (T, T) {
+        let one = T::Scalar::from_i32(1);
+        let two = T::Scalar::from_i32(2);
+        let incoming = self.point - previous;
+        let outgoing = next - self.point;
+
+        let scale = (one - self.tension) / two;
+        let before = scale * (one + self.bias);
+        let after = scale * (one - self.bias);
+
+        (
+            incoming * (before * (one + self.continuity)) + outgoing * (after * (one - self.continuity)),
+            incoming * (before * (one - self.continuity)) + outgoing * (after * (one + self.continuity)),
+        )
+    }
+}
+
+impl<T: InnerProductSpace> Spline<CubicCurve<T>> {
+    // Builds a spline that passes through every key, with one segment between each pair of consecutive keys
+    pub fn kochanek_bartels(keys: &[TcbKey<T>], boundary: CatmullRomBoundary<T>) -> Spline<CubicCurve<T>> {
+        let n = keys.len();
+        if n < 2 {
+            return Spline::new(Vec::new());
+        }
+
+        let points = keys.iter().map(TcbKey::point).collect::<Vec<_>>();
+        let point = |i: isize| boundary_point(&points, boundary, i);
+        let key = |i: usize| keys[i % n];
+
+        let closed = matches!(boundary, CatmullRomBoundary::Closed);
+        let segment_count = if closed { n } else { n - 1 };
+        let mut segments = Vec::with_capacity(segment_count);
+        for index in 0..segment_count {
+            let i = index as isize;
+            let (_, mut m0) = key(index).tangents(point(i - 1), point(i + 1));
+            let (mut m1, _) = key(index + 1).tangents(point(i), point(i + 2));
+
+            if let CatmullRomBoundary::Tangents(start, end) = boundary {
+                if index == 0 {
+                    m0 = start;
+                }
+                if index == segment_count - 1 {
+                    m1 = end;
+                }
+            }
+
+            segments.push(CubicCurve::from_hermite(point(i), m0, point(i + 1), m1));
+        }
+
+        Spline::new(segments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod kochanek_bartels {
+        use crate::{
+            CatmullRomBoundary,
+            CatmullRomParameterization,
+            Spline,
+            TcbKey,
+            Vector2,
+        };
+        use approx::assert_abs_diff_eq;
+
+        // the keys of a path that doubles back on itself, with uneven spacing
+        fn points() -> [Vector2<f32>; 5] {
+            [
+                Vector2::new(0f32, 0f32),
+                Vector2::new(3f32, 1f32),
+                Vector2::new(2f32, 3f32),
+                Vector2::new(-1f32, 2.5f32),
+                Vector2::new(0.5f32, 5f32),
+            ]
+        }
+
+        fn keys(tension: f32, continuity: f32, bias: f32) -> Vec<TcbKey<Vector2<f32>>> {
+            points().iter().map(|&point| TcbKey::new(point, tension, continuity, bias)).collect()
+        }
+
+        #[test]
+        fn catmull_rom() {
+            let boundaries = [
+                CatmullRomBoundary::Closed,
+                CatmullRomBoundary::Reflected,
+                CatmullRomBoundary::Duplicated,
+                CatmullRomBoundary::Tangents(Vector2::new(1f32, 0f32), Vector2::new(0f32, -1f32)),
+            ];
+            for &boundary in &boundaries {
+                let tcb = Spline::kochanek_bartels(&keys(0f32, 0f32, 0f32), boundary);
+                let catmull_rom = Spline::catmull_rom(&points(), CatmullRomParameterization::Uniform, boundary);
+                assert_eq!(tcb.len(), catmull_rom.len());
+                for (left, right) in tcb.segments().iter().zip(catmull_rom.segments().iter()) {
+                    let (left, right) = (left.to_bezier(), right.to_bezier());
+                    for &(a, b) in &[(left.0, right.0), (left.1, right.1), (left.2, right.2), (left.3, right.3)] {
+                        assert_abs_diff_eq!(a.x, b.x, epsilon = 0.00001f32);
+                        assert_abs_diff_eq!(a.y, b.y, epsilon = 0.00001f32);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn tension() {
+            let points = points();
+
+            // full tension stops the curve at every key
+            let spline = Spline::kochanek_bartels(&keys(1f32, 0f32, 0f32), CatmullRomBoundary::Reflected);
+            for (i, &point) in points.iter().enumerate() {
+                assert_eq!(spline.evaluate(i as f32).unwrap(), point);
+                assert_eq!(spline.evaluate_derivative(i as f32).unwrap(), Vector2::new(0f32, 0f32));
+            }
+
+            // negative tension lengthens the tangents
+            let loose = Spline::kochanek_bartels(&keys(-1f32, 0f32, 0f32), CatmullRomBoundary::Reflected);
+            assert_eq!(loose.segments()[1].evaluate_first_derivative(0f32), points[2] - points[0]);
+        }
+
+        #[test]
+        fn continuity() {
+            let points = points();
+            let spline = Spline::kochanek_bartels(&keys(0f32, 1f32, 0f32), CatmullRomBoundary::Reflected);
+            // full continuity makes each side of a key point along its own neighbor
+            for i in 1..4 {
+                assert_eq!(spline.segments()[i - 1].evaluate_first_derivative(1f32), points[i] - points[i - 1]);
+                assert_eq!(spline.segments()[i].evaluate_first_derivative(0f32), points[i + 1] - points[i]);
+            }
+
+            let spline = Spline::kochanek_bartels(&keys(0f32, -1f32, 0f32), CatmullRomBoundary::Reflected);
+            for i in 1..4 {
+                assert_eq!(spline.segments()[i - 1].evaluate_first_derivative(1f32), points[i + 1] - points[i]);
+                assert_eq!(spline.segments()[i].evaluate_first_derivative(0f32), points[i] - points[i - 1]);
+            }
+        }
+
+        #[test]
+        fn bias() {
+            let points = points();
+            let spline = Spline::kochanek_bartels(&keys(0f32, 0f32, 1f32), CatmullRomBoundary::Reflected);
+            for i in 1..4 {
+                assert_eq!(spline.segments()[i].evaluate_first_derivative(0f32), points[i] - points[i - 1]);
+            }
+
+            let spline = Spline::kochanek_bartels(&keys(0f32, 0f32, -1f32), CatmullRomBoundary::Reflected);
+            for i in 1..4 {
+                assert_eq!(spline.segments()[i].evaluate_first_derivative(0f32), points[i + 1] - points[i]);
+            }
+
+            // each key only affects the tangents at its own point
+            let mut keys = keys(0f32, 0f32, 0f32);
+            keys[2] = TcbKey::new(points[2], 0.5f32, -0.5f32, 0.25f32);
+            let modified = Spline::kochanek_bartels(&keys, CatmullRomBoundary::Reflected);
+            let original = Spline::catmull_rom(&points, CatmullRomParameterization::Uniform, CatmullRomBoundary::Reflected);
+            assert_eq!(modified.segments()[0], original.segments()[0]);
+            assert_eq!(modified.segments()[3], original.segments()[3]);
+            assert!(modified.segments()[1] != original.segments()[1]);
+        }
+    }
+}
\ No newline at end of file
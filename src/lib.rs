@@ -1,12 +1,62 @@
 mod vector2;
+mod vector3;
+mod vector4;
+mod b_spline;
+mod bezier_curve;
+mod bounding_box;
+mod catmull_rom;
 mod cubic_curve;
+mod cubic_spline;
+mod curvature;
+mod curve;
+mod fitting;
+mod flatten;
+mod kochanek_bartels;
 mod linear_curve;
+mod monotonic;
+mod nurbs;
+mod polynomial_curve;
 mod quadratic_curve;
-mod roots;
+mod quadrature;
+mod rational_bezier_curve;
+pub mod roots;
+mod spline;
 
+pub use self::b_spline::BSpline;
+pub use self::bezier_curve::BezierCurve;
+pub use self::bounding_box::{
+    BoundingBox,
+    OrientedBoundingBox,
+};
+pub use self::catmull_rom::{
+    CatmullRomBoundary,
+    CatmullRomParameterization,
+};
 pub use self::cubic_curve::CubicCurve;
-pub use self::linear_curve::LinearCurve;
+pub use self::cubic_spline::CubicSplineBoundary;
+pub use self::curve::Curve;
+pub use self::fitting::{
+    chord_length_parameters,
+    max_error,
+    reparameterize,
+};
+pub use self::flatten::{
+    CubicFlattening,
+    QuadraticFlattening,
+};
+pub use self::kochanek_bartels::TcbKey;
+pub use self::linear_curve::{
+    LinearApproximation,
+    LinearCurve,
+};
+pub use self::nurbs::Nurbs;
+pub use self::polynomial_curve::PolynomialCurve;
 pub use self::quadratic_curve::QuadraticCurve;
+pub use self::rational_bezier_curve::RationalBezierCurve;
+pub use self::spline::Spline;
+pub use self::vector2::Vector2;
+pub use self::vector3::Vector3;
+pub use self::vector4::Vector4;
 
 use std::{
     cmp::PartialOrd,
@@ -35,6 +85,38 @@ pub trait Scalar: Add<Output = Self> + AddAssign + Clone + Copy + Debug + Div<Ou
     fn ln(self) -> Self;
     fn pi() -> Self;
     fn sqrt(self) -> Self;
+
+    // The default implementations only handle values in the range of an i32, and should be replaced when the scalar
+    // type has a faster way to do them
+    fn floor(self) -> Self {
+        let truncated = Self::from_i32(self.to_i32());
+        if truncated > self {
+            truncated - Self::from_i32(1)
+        } else {
+            truncated
+        }
+    }
+
+    // Rounds toward zero and saturates, like casting a float to an i32
+    fn to_i32(self) -> i32 {
+        // binary search for the largest integer no greater than the magnitude
+        let magnitude = self.abs();
+        let mut lo = 0;
+        let mut hi = i32::MAX;
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2 + 1;
+            if Self::from_i32(mid) <= magnitude {
+                lo = mid;
+            } else {
+                hi = mid - 1;
+            }
+        }
+        if self < Self::from_i32(0) {
+            -lo
+        } else {
+            lo
+        }
+    }
 }
 
 pub trait VectorSpace: Add<Output = Self> + AddAssign + Clone + Copy + Div<<Self as VectorSpace>::Scalar, Output = Self> + DivAssign<<Self as VectorSpace>::Scalar> + Mul<<Self as VectorSpace>::Scalar, Output = Self> + MulAssign<<Self as VectorSpace>::Scalar> + Neg + Sub<Output = Self> + SubAssign {
@@ -60,6 +142,9 @@ impl Scalar for f32 {
     fn cos(self) -> Self {
         self.cos()
     }
+    fn floor(self) -> Self {
+        self.floor()
+    }
     fn from_i32(value: i32) -> Self {
         value as f32
     }
@@ -72,6 +157,9 @@ impl Scalar for f32 {
     fn sqrt(self) -> Self {
         self.sqrt()
     }
+    fn to_i32(self) -> i32 {
+        self as i32
+    }
 }
 
 impl VectorSpace for f32 {
@@ -101,6 +189,9 @@ impl Scalar for f64 {
     fn cos(self) -> Self {
         self.cos()
     }
+    fn floor(self) -> Self {
+        self.floor()
+    }
     fn from_i32(value: i32) -> Self {
         value as f64
     }
@@ -113,6 +204,9 @@ impl Scalar for f64 {
     fn sqrt(self) -> Self {
         self.sqrt()
     }
+    fn to_i32(self) -> i32 {
+        self as i32
+    }
 }
 
 impl VectorSpace for f64 {
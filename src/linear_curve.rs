@@ -1,77 +1,93 @@
 use crate::{
     InnerProductSpace,
+    PolynomialCurve,
     QuadraticCurve,
     Scalar,
 };
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LinearApproximation {
+    // half the error of the continuity-preserving approximation, but consecutive lines do not meet
+    ErrorMinimizing,
+    // each line starts where the one before it ends
+    ContinuityPreserving,
+}
+
+impl LinearApproximation {
+    // The maximum error of approximating a quadratic curve, as a multiple of the length of its second coefficient
+    pub fn error_factor<T: Scalar>(self) -> T {
+        match self {
+            LinearApproximation::ErrorMinimizing => T::from_i32(1) / T::from_i32(8),
+            LinearApproximation::ContinuityPreserving => T::from_i32(1) / T::from_i32(4),
+        }
+    }
+}
+
+// A PolynomialCurve of degree one, x0 + x1 t
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct LinearCurve<T: InnerProductSpace> {
-    x0: T,
-    x1: T,
+    curve: PolynomialCurve<T, 2>,
 }
 
 impl<T: InnerProductSpace> LinearCurve<T> {
     pub fn new(x0: T, x1: T) -> LinearCurve<T> {
         LinearCurve {
-            x0,
-            x1,
+            curve: PolynomialCurve::new([x0, x1]),
         }
     }
 
     pub fn x0(&self) -> T {
-        self.x0
+        self.curve.coefficients()[0]
     }
 
     pub fn x1(&self) -> T {
-        self.x1
+        self.curve.coefficients()[1]
     }
 
     pub fn evaluate(&self, t: T::Scalar) -> T {
-        self.x0 + self.x1 * t
+        self.curve.evaluate(t)
     }
 
     pub fn evaluate_derivative(&self) -> T {
-        self.x1
+        self.x1()
     }
 
     pub fn from_bezier(b0: T, b1: T) -> LinearCurve<T> {
-        LinearCurve {
-            x0: b0,
-            x1: b1 - b0
-        }
+        LinearCurve::new(
+            b0,
+            b1 - b0,
+        )
     }
 
     pub fn to_bezier(&self) -> (T, T) {
         (
-            self.x0,
-            self.x0 + self.x1,
+            self.x0(),
+            self.x0() + self.x1(),
         )
     }
 
     pub fn subdivide(&self, u: T::Scalar) -> (LinearCurve<T>, LinearCurve<T>) {
-        let x1u = self.x1 * u;
-
+        let (left, right) = self.curve.subdivide(u);
         (
-            LinearCurve::new(
-                self.x0,
-                x1u,
-            ),
-            LinearCurve::new(
-                self.x0 + x1u,
-                x1u,
-            )
+            LinearCurve {
+                curve: left,
+            },
+            LinearCurve {
+                curve: right,
+            },
         )
     }
 
     pub fn to_quadratic(&self) -> QuadraticCurve<T> {
         QuadraticCurve::new(
-            self.x0,
-            self.x1,
+            self.x0(),
+            self.x1(),
             T::zero(),
         )
     }
 
     pub fn speed(&self) -> T::Scalar {
-        self.x1.inner_product(self.x1).sqrt()
+        self.x1().inner_product(self.x1()).sqrt()
     }
 
     pub fn length(&self, u: T::Scalar) -> T::Scalar {
@@ -82,12 +98,87 @@ impl<T: InnerProductSpace> LinearCurve<T> {
         let speed = self.speed();
         if speed == T::Scalar::from_i32(0) {
             None
+        } else if v < T::Scalar::from_i32(0) {
+            Some(T::Scalar::from_i32(0))
+        } else if v > speed {
+            Some(T::Scalar::from_i32(1))
         } else {
-            Some(v / self.speed())
+            Some(v / speed)
         }
     }
 
     pub fn nearest_point(&self, q: T) -> T::Scalar {
-        self.x1.inner_product(q - self.x0)
+        let a = self.x1().inner_product(self.x1());
+        if a == T::Scalar::from_i32(0) {
+            T::Scalar::from_i32(0)
+        } else {
+            let t = self.x1().inner_product(q - self.x0()) / a;
+            if t < T::Scalar::from_i32(0) {
+                T::Scalar::from_i32(0)
+            } else if t > T::Scalar::from_i32(1) {
+                T::Scalar::from_i32(1)
+            } else {
+                t
+            }
+        }
+    }
+}
+
+impl<T: InnerProductSpace> From<LinearCurve<T>> for PolynomialCurve<T, 2> {
+    fn from(curve: LinearCurve<T>) -> Self {
+        curve.curve
+    }
+}
+
+impl<T: InnerProductSpace> From<PolynomialCurve<T, 2>> for LinearCurve<T> {
+    fn from(curve: PolynomialCurve<T, 2>) -> Self {
+        LinearCurve {
+            curve,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod linear_curve {
+        use crate::{
+            LinearCurve,
+            Vector2,
+        };
+        use approx::assert_ulps_eq;
+
+        #[test]
+        fn subdivide() {
+            let curve = LinearCurve::new(1f32, 2f32);
+            let (left, right) = curve.subdivide(0.25f32);
+
+            assert_ulps_eq!(left.x0(), 1f32);
+            assert_ulps_eq!(left.x1(), 0.5f32);
+
+            assert_ulps_eq!(right.x0(), 1.5f32);
+            assert_ulps_eq!(right.x1(), 1.5f32);
+        }
+
+        #[test]
+        fn parameterize() {
+            let curve = LinearCurve::new(1f32, 2f32);
+            assert_eq!(curve.parameterize(1f32), Some(0.5f32));
+            assert_eq!(curve.parameterize(3f32), Some(1f32));
+            assert_eq!(curve.parameterize(-1f32), Some(0f32));
+
+            let curve = LinearCurve::new(1f32, 0f32);
+            assert_eq!(curve.parameterize(1f32), None);
+        }
+
+        #[test]
+        fn nearest_point() {
+            let curve = LinearCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(2f32, 0f32));
+            assert_ulps_eq!(curve.nearest_point(Vector2::new(0.5f32, 1f32)), 0.25f32);
+            assert_ulps_eq!(curve.nearest_point(Vector2::new(-1f32, 1f32)), 0f32);
+            assert_ulps_eq!(curve.nearest_point(Vector2::new(3f32, -1f32)), 1f32);
+
+            let curve = LinearCurve::new(1f32, 0f32);
+            assert_ulps_eq!(curve.nearest_point(2f32), 0f32);
+        }
     }
 }
\ No newline at end of file
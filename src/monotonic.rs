@@ -0,0 +1,156 @@
+use crate::{
+    CubicCurve,
+    Curve,
+    QuadraticCurve,
+    Scalar,
+    Vector2,
+    VectorSpace,
+    roots::Roots,
+};
+
+// Splits the curve at each of the sorted parameters that are strictly inside it
+fn split<C: Curve>(curve: C, parameters: &[<C::Vector as VectorSpace>::Scalar]) -> Vec<C> {
+    let zero = <C::Vector as VectorSpace>::Scalar::from_i32(0);
+    let one = <C::Vector as VectorSpace>::Scalar::from_i32(1);
+
+    let mut pieces = Vec::with_capacity(parameters.len() + 1);
+    let mut remaining = curve;
+    let mut start = zero;
+    for &t in parameters.iter() {
+        if start < t && t < one {
+            let (piece, rest) = remaining.subdivide((t - start) / (one - start));
+            pieces.push(piece);
+            remaining = rest;
+            start = t;
+        }
+    }
+    pieces.push(remaining);
+    pieces
+}
+
+// The parameters where either coordinate of the curve stops increasing or decreasing, sorted and without duplicates
+fn axis_extrema<S: Scalar, F: Fn(Vector2<S>) -> Roots<S>>(extrema: F) -> Roots<S> {
+    // each coordinate of a cubic has at most two extrema
+    let mut parameters = [S::from_i32(0); 4];
+    let mut len = 0;
+    let x = extrema(Vector2::new(S::from_i32(1), S::from_i32(0)));
+    let y = extrema(Vector2::new(S::from_i32(0), S::from_i32(1)));
+    for &t in x.iter().chain(y.iter()) {
+        parameters[len] = t;
+        len += 1;
+    }
+    Roots::from_slice(&parameters[..len]).restrict(S::from_i32(0), S::from_i32(1))
+}
+
+impl<S: Scalar> QuadraticCurve<Vector2<S>> {
+    // The parameters of the extrema along the x and y axes, sorted and without duplicates
+    pub fn axis_extrema(&self) -> Roots<S> {
+        axis_extrema(|direction| self.extrema(direction))
+    }
+
+    // Splits the curve at its axis extrema, so that each piece only moves one way along each axis
+    pub fn split_monotonic(&self) -> Vec<QuadraticCurve<Vector2<S>>> {
+        split(*self, self.axis_extrema().as_slice())
+    }
+}
+
+impl<S: Scalar> CubicCurve<Vector2<S>> {
+    // The parameters of the extrema along the x and y axes, sorted and without duplicates
+    pub fn axis_extrema(&self) -> Roots<S> {
+        axis_extrema(|direction| self.extrema(direction))
+    }
+
+    // Splits the curve at its axis extrema, so that each piece only moves one way along each axis
+    pub fn split_monotonic(&self) -> Vec<CubicCurve<Vector2<S>>> {
+        split(*self, self.axis_extrema().as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod monotonic {
+        use crate::{
+            CubicCurve,
+            QuadraticCurve,
+            Vector2,
+        };
+        use approx::{
+            assert_abs_diff_eq,
+            assert_ulps_eq,
+        };
+
+        // whether the coordinate never changes direction, allowing for rounding near the ends
+        fn monotonic<F: Fn(f32) -> f32>(coordinate: F) -> bool {
+            let values = (0..=100).map(|i| coordinate(i as f32 / 100f32)).collect::<Vec<_>>();
+            values.windows(2).all(|pair| pair[1] >= pair[0] - 0.00001f32) || values.windows(2).all(|pair| pair[1] <= pair[0] + 0.00001f32)
+        }
+
+        #[test]
+        fn extrema() {
+            let curve = QuadraticCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32), Vector2::new(3f32, -1f32));
+            assert!(curve.extrema(Vector2::new(1f32, 0f32)).is_empty());
+            let roots = curve.extrema(Vector2::new(0f32, 1f32));
+            assert_eq!(roots.len(), 1);
+            assert_ulps_eq!(roots.as_slice()[0], 0.4f32);
+            assert_eq!(curve.axis_extrema().len(), 1);
+
+            // extrema shared by both axes are only reported once, and NaN never is
+            let curve = QuadraticCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 1f32), Vector2::new(0f32, 0f32));
+            assert_eq!(curve.axis_extrema().as_slice(), &[0.5f32]);
+            let curve = QuadraticCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(f32::NAN, 1f32), Vector2::new(0f32, 0f32));
+            assert_eq!(curve.axis_extrema().as_slice(), &[] as &[f32]);
+
+            let curve = CubicCurve::from_bezier(
+                Vector2::new(0f32, 0f32),
+                Vector2::new(-1f32, 3f32),
+                Vector2::new(4f32, -2f32),
+                Vector2::new(3f32, 1f32),
+            );
+            let parameters = curve.axis_extrema();
+            assert_eq!(parameters.len(), 4);
+            assert!(parameters.as_slice().windows(2).all(|pair| pair[0] < pair[1]));
+            for &t in parameters.iter() {
+                let derivative = curve.evaluate_first_derivative(t);
+                assert!(derivative.x.abs() < 0.0001f32 || derivative.y.abs() < 0.0001f32);
+            }
+        }
+
+        #[test]
+        fn split_monotonic() {
+            let curve = QuadraticCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32), Vector2::new(3f32, -1f32));
+            let pieces = curve.split_monotonic();
+            assert_eq!(pieces.len(), 2);
+            assert_ulps_eq!(pieces[0].evaluate(1f32).y, 0.8f32);
+            for piece in pieces.iter() {
+                assert!(monotonic(|t| piece.evaluate(t).x));
+                assert!(monotonic(|t| piece.evaluate(t).y));
+            }
+
+            let curve = CubicCurve::from_bezier(
+                Vector2::new(0f32, 0f32),
+                Vector2::new(-1f32, 3f32),
+                Vector2::new(4f32, -2f32),
+                Vector2::new(3f32, 1f32),
+            );
+            let pieces = curve.split_monotonic();
+            assert_eq!(pieces.len(), 5);
+            assert_eq!(pieces[0].evaluate(0f32), curve.evaluate(0f32));
+            let end = pieces[4].evaluate(1f32);
+            assert_abs_diff_eq!(end.x, 3f32, epsilon = 0.00001f32);
+            assert_abs_diff_eq!(end.y, 1f32, epsilon = 0.00001f32);
+            for (i, piece) in pieces.iter().enumerate() {
+                assert!(monotonic(|t| piece.evaluate(t).x));
+                assert!(monotonic(|t| piece.evaluate(t).y));
+                if i > 0 {
+                    let (end, start) = (pieces[i - 1].evaluate(1f32), piece.evaluate(0f32));
+                    assert_abs_diff_eq!(end.x, start.x, epsilon = 0.00001f32);
+                    assert_abs_diff_eq!(end.y, start.y, epsilon = 0.00001f32);
+                }
+            }
+
+            // a monotonic curve is left alone
+            let curve = CubicCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 1f32), Vector2::new(2f32, 1f32), Vector2::new(3f32, 3f32));
+            assert_eq!(curve.split_monotonic(), vec![curve]);
+        }
+    }
+}
\ No newline at end of file
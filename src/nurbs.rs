@@ -0,0 +1,207 @@
+use crate::{
+    BSpline,
+    InnerProductSpace,
+    RationalBezierCurve,
+    Scalar,
+    b_spline::elevate,
+};
+
+// A rational B-spline, stored as a B-spline of weighted control points and a B-spline of their weights over the same
+// knots
+#[derive(Clone, Debug, PartialEq)]
+pub struct Nurbs<T: InnerProductSpace>
+where
+    T::Scalar: InnerProductSpace<Scalar = T::Scalar>,
+{
+    numerator: BSpline<T>,
+    denominator: BSpline<T::Scalar>,
+}
+
+impl<T: InnerProductSpace> Nurbs<T>
+where
+    T::Scalar: InnerProductSpace<Scalar = T::Scalar>,
+{
+    pub fn new(degree: usize, knots: Vec<T::Scalar>, control_points: Vec<T>, weights: Vec<T::Scalar>) -> Nurbs<T> {
+        assert_eq!(control_points.len(), weights.len(), "there must be one weight for each control point");
+        assert!(weights.iter().all(|&weight| weight > T::Scalar::from_i32(0)), "weights must be positive");
+
+        let weighted = control_points.iter().zip(weights.iter()).map(|(&point, &weight)| point * weight).collect();
+        Nurbs {
+            numerator: BSpline::new(degree, knots.clone(), weighted),
+            denominator: BSpline::new(degree, knots, weights),
+        }
+    }
+
+    pub fn degree(&self) -> usize {
+        self.numerator.degree()
+    }
+
+    pub fn knots(&self) -> &[T::Scalar] {
+        self.numerator.knots()
+    }
+
+    pub fn control_points(&self) -> Vec<T> {
+        self.numerator.control_points()
+            .iter()
+            .zip(self.weights().iter())
+            .map(|(&point, &weight)| point / weight)
+            .collect()
+    }
+
+    pub fn weights(&self) -> &[T::Scalar] {
+        self.denominator.control_points()
+    }
+
+    pub fn domain(&self) -> (T::Scalar, T::Scalar) {
+        self.numerator.domain()
+    }
+
+    // Clamps to the domain
+    pub fn evaluate(&self, t: T::Scalar) -> T {
+        self.numerator.evaluate(t) / self.denominator.evaluate(t)
+    }
+
+    pub fn evaluate_derivative(&self, t: T::Scalar) -> T {
+        // the quotient rule
+        let weight = self.denominator.evaluate(t);
+        let point = self.numerator.evaluate(t) / weight;
+        (self.numerator.evaluate_derivative(t) - point * self.denominator.evaluate_derivative(t)) / weight
+    }
+
+    pub fn insert_knot(&mut self, t: T::Scalar) {
+        self.numerator.insert_knot(t);
+        self.denominator.insert_knot(t);
+    }
+
+    fn to_rational_bezier<const N: usize>(&self) -> Vec<RationalBezierCurve<T, N>> {
+        self.numerator.to_bezier()
+            .into_iter()
+            .zip(self.denominator.to_bezier())
+            .map(|(points, weights)| {
+                let points = elevate(points, N);
+                let weights = elevate(weights, N);
+
+                let mut control_points = [T::zero(); N];
+                let mut control_weights = [T::Scalar::from_i32(0); N];
+                for i in 0..N {
+                    control_points[i] = points[i] / weights[i];
+                    control_weights[i] = weights[i];
+                }
+                RationalBezierCurve::new(control_points, control_weights)
+            })
+            .collect()
+    }
+
+    // Each nonempty knot span becomes one curve
+    pub fn to_rational_quadratics(&self) -> Vec<RationalBezierCurve<T, 3>> {
+        assert!(self.degree() <= 2, "only splines of degree two or less can be converted to rational quadratic curves");
+        self.to_rational_bezier()
+    }
+
+    // Each nonempty knot span becomes one curve
+    pub fn to_rational_cubics(&self) -> Vec<RationalBezierCurve<T, 4>> {
+        assert!(self.degree() <= 3, "only splines of degree three or less can be converted to rational cubic curves");
+        self.to_rational_bezier()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod nurbs {
+        use crate::{
+            BSpline,
+            Nurbs,
+            Vector2,
+        };
+        use approx::{
+            assert_abs_diff_eq,
+            assert_ulps_eq,
+        };
+
+        fn circle() -> Nurbs<Vector2<f32>> {
+            let w = 0.5f32.sqrt();
+            Nurbs::new(
+                2,
+                vec![0f32, 0f32, 0f32, 1f32, 1f32, 2f32, 2f32, 3f32, 3f32, 4f32, 4f32, 4f32],
+                vec![
+                    Vector2::new(1f32, 0f32),
+                    Vector2::new(1f32, 1f32),
+                    Vector2::new(0f32, 1f32),
+                    Vector2::new(-1f32, 1f32),
+                    Vector2::new(-1f32, 0f32),
+                    Vector2::new(-1f32, -1f32),
+                    Vector2::new(0f32, -1f32),
+                    Vector2::new(1f32, -1f32),
+                    Vector2::new(1f32, 0f32),
+                ],
+                vec![1f32, w, 1f32, w, 1f32, w, 1f32, w, 1f32],
+            )
+        }
+
+        #[test]
+        fn evaluate() {
+            let circle = circle();
+            assert_eq!(circle.domain(), (0f32, 4f32));
+            for i in 0..=40 {
+                let t = i as f32 / 10f32;
+                assert_ulps_eq!(circle.evaluate(t).length(), 1f32, max_ulps = 4);
+
+                // the tangent of a circle is perpendicular to the radius
+                let point = circle.evaluate(t);
+                let derivative = circle.evaluate_derivative(t);
+                assert_abs_diff_eq!(point.x * derivative.x + point.y * derivative.y, 0f32, epsilon = 0.00001f32);
+            }
+            assert_ulps_eq!(circle.evaluate(1f32).y, 1f32);
+            assert_ulps_eq!(circle.evaluate(2f32).x, -1f32);
+
+            // unit weights are an ordinary B-spline
+            let points = vec![Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32), Vector2::new(2f32, -1f32), Vector2::new(4f32, 3f32), Vector2::new(5f32, 0f32)];
+            let spline = BSpline::uniform(3, points.clone());
+            let nurbs = Nurbs::new(3, spline.knots().to_vec(), points, vec![1f32; 5]);
+            for i in 0..=10 {
+                let t = 3f32 + i as f32 / 5f32;
+                assert_abs_diff_eq!(nurbs.evaluate(t).x, spline.evaluate(t).x, epsilon = 0.00001f32);
+                assert_abs_diff_eq!(nurbs.evaluate(t).y, spline.evaluate(t).y, epsilon = 0.00001f32);
+                assert_abs_diff_eq!(nurbs.evaluate_derivative(t).x, spline.evaluate_derivative(t).x, epsilon = 0.0001f32);
+                assert_abs_diff_eq!(nurbs.evaluate_derivative(t).y, spline.evaluate_derivative(t).y, epsilon = 0.0001f32);
+            }
+        }
+
+        #[test]
+        fn insert_knot() {
+            let circle = circle();
+            let mut inserted = circle.clone();
+            inserted.insert_knot(0.5f32);
+            inserted.insert_knot(2.25f32);
+            assert_eq!(inserted.control_points().len(), 11);
+            assert_eq!(inserted.weights().len(), 11);
+            for i in 0..=40 {
+                let t = i as f32 / 10f32;
+                assert_abs_diff_eq!(inserted.evaluate(t).x, circle.evaluate(t).x, epsilon = 0.00001f32);
+                assert_abs_diff_eq!(inserted.evaluate(t).y, circle.evaluate(t).y, epsilon = 0.00001f32);
+            }
+        }
+
+        #[test]
+        fn bezier_extraction() {
+            let circle = circle();
+            let w = 0.5f32.sqrt();
+
+            let quadratics = circle.to_rational_quadratics();
+            assert_eq!(quadratics.len(), 4);
+            assert_eq!(quadratics[1].points(), [Vector2::new(0f32, 1f32), Vector2::new(-1f32, 1f32), Vector2::new(-1f32, 0f32)]);
+            assert_eq!(quadratics[1].weights(), [1f32, w, 1f32]);
+
+            let cubics = circle.to_rational_cubics();
+            assert_eq!(cubics.len(), 4);
+            for (i, cubic) in cubics.iter().enumerate() {
+                for j in 0..=10 {
+                    let u = j as f32 / 10f32;
+                    assert_ulps_eq!(cubic.evaluate(u).length(), 1f32, max_ulps = 4);
+                    assert_abs_diff_eq!(cubic.evaluate(u).x, circle.evaluate(i as f32 + u).x, epsilon = 0.00001f32);
+                    assert_abs_diff_eq!(cubic.evaluate(u).y, circle.evaluate(i as f32 + u).y, epsilon = 0.00001f32);
+                }
+            }
+        }
+    }
+}
\ No newline at end of file
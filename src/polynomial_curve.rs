@@ -0,0 +1,308 @@
+use crate::{
+    InnerProductSpace,
+    Scalar,
+};
+
+pub fn binomial<T: Scalar>(n: usize, k: usize) -> T {
+    let mut result = T::from_i32(1);
+    for i in 0..k {
+        result = result * T::from_i32((n - i) as i32) / T::from_i32(i as i32 + 1);
+    }
+    result
+}
+
+// The curve has N coefficients, and so is of degree N - 1
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PolynomialCurve<T: InnerProductSpace, const N: usize> {
+    coefficients: [T; N],
+}
+
+impl<T: InnerProductSpace, const N: usize> PolynomialCurve<T, N> {
+    pub fn new(coefficients: [T; N]) -> PolynomialCurve<T, N> {
+        assert!(N > 0, "a curve needs at least one coefficient");
+
+        PolynomialCurve {
+            coefficients,
+        }
+    }
+
+    pub fn coefficients(&self) -> [T; N] {
+        self.coefficients
+    }
+
+    pub fn degree(&self) -> usize {
+        N - 1
+    }
+
+    pub fn evaluate(&self, t: T::Scalar) -> T {
+        let mut result = T::zero();
+        for &coefficient in self.coefficients.iter().rev() {
+            result = result * t + coefficient;
+        }
+        result
+    }
+
+    // The derivative has the same number of coefficients, with the highest one always zero
+    pub fn derivative(&self) -> PolynomialCurve<T, N> {
+        let mut coefficients = [T::zero(); N];
+        for k in 1..N {
+            coefficients[k - 1] = self.coefficients[k] * T::Scalar::from_i32(k as i32);
+        }
+        PolynomialCurve::new(coefficients)
+    }
+
+    pub fn evaluate_derivative(&self, t: T::Scalar) -> T {
+        let mut result = T::zero();
+        for k in (1..N).rev() {
+            result = result * t + self.coefficients[k] * T::Scalar::from_i32(k as i32);
+        }
+        result
+    }
+
+    pub fn from_bezier(b: [T; N]) -> PolynomialCurve<T, N> {
+        let n = N - 1;
+        let mut coefficients = [T::zero(); N];
+        for (k, coefficient) in coefficients.iter_mut().enumerate() {
+            let mut sum = T::zero();
+            for (i, &bi) in b[..=k].iter().enumerate() {
+                let term = bi * binomial::<T::Scalar>(k, i);
+                if (k - i) % 2 == 0 {
+                    sum += term;
+                } else {
+                    sum -= term;
+                }
+            }
+            *coefficient = sum * binomial::<T::Scalar>(n, k);
+        }
+        PolynomialCurve::new(coefficients)
+    }
+
+    pub fn to_bezier(&self) -> [T; N] {
+        let n = N - 1;
+        let mut b = [T::zero(); N];
+        for (i, bi) in b.iter_mut().enumerate() {
+            for (k, &xk) in self.coefficients[..=i].iter().enumerate() {
+                *bi += xk * (binomial::<T::Scalar>(i, k) / binomial::<T::Scalar>(n, k));
+            }
+        }
+        b
+    }
+
+    pub fn subdivide(&self, u: T::Scalar) -> (PolynomialCurve<T, N>, PolynomialCurve<T, N>) {
+        let w = T::Scalar::from_i32(1) - u;
+
+        let mut left = [T::zero(); N];
+        let mut right = [T::zero(); N];
+        let mut uk = T::Scalar::from_i32(1);
+        let mut wk = T::Scalar::from_i32(1);
+        for k in 0..N {
+            left[k] = self.coefficients[k] * uk;
+
+            let mut sum = T::zero();
+            let mut ujk = T::Scalar::from_i32(1);
+            for j in k..N {
+                sum += self.coefficients[j] * (binomial::<T::Scalar>(j, k) * ujk);
+                ujk *= u;
+            }
+            right[k] = sum * wk;
+
+            uk *= u;
+            wk *= w;
+        }
+
+        (
+            PolynomialCurve::new(left),
+            PolynomialCurve::new(right),
+        )
+    }
+
+    // Exact, the additional coefficients are zero
+    pub fn elevate_degree<const M: usize>(&self) -> PolynomialCurve<T, M> {
+        assert!(M >= N, "cannot elevate a curve with {} coefficients to {} coefficients", N, M);
+        let mut coefficients = [T::zero(); M];
+        coefficients[..N].copy_from_slice(&self.coefficients);
+        PolynomialCurve::new(coefficients)
+    }
+
+    // Repeatedly removes the highest degree term using its error-minimizing approximation
+    pub fn reduce_degree<const M: usize>(&self) -> PolynomialCurve<T, M> {
+        let (coefficients, _) = self.reduce_coefficients(M);
+        let mut result = [T::zero(); M];
+        result.copy_from_slice(&coefficients[..M]);
+        PolynomialCurve::new(result)
+    }
+
+    // An upper bound on the distance between the curve and its reduction to M coefficients
+    pub fn reduce_degree_error<const M: usize>(&self) -> T::Scalar {
+        let (_, error) = self.reduce_coefficients(M);
+        error
+    }
+
+    fn reduce_coefficients(&self, m: usize) -> ([T; N], T::Scalar) {
+        assert!(m >= 1 && m <= N, "cannot reduce a curve with {} coefficients to {} coefficients", N, m);
+        let mut coefficients = self.coefficients;
+        let mut error = T::Scalar::from_i32(0);
+        for n in (m..N).rev() {
+            // the shifted Chebyshev polynomial has a maximum magnitude of 1 on [0, 1]
+            let chebyshev = shifted_chebyshev::<T::Scalar, N>(n);
+            let scale = coefficients[n] / chebyshev[n];
+            error += scale.inner_product(scale).sqrt();
+            for (coefficient, &c) in coefficients[..=n].iter_mut().zip(chebyshev[..=n].iter()) {
+                *coefficient -= scale * c;
+            }
+        }
+        (coefficients, error)
+    }
+}
+
+// Power basis coefficients of the shifted Chebyshev polynomial T_n(2t - 1), which has a leading coefficient of 2^(2n - 1)
+fn shifted_chebyshev<T: Scalar, const N: usize>(n: usize) -> [T; N] {
+    let mut previous = [T::from_i32(0); N];
+    let mut current = [T::from_i32(0); N];
+    previous[0] = T::from_i32(1);
+    current[0] = T::from_i32(-1);
+    if N > 1 {
+        current[1] = T::from_i32(2);
+    }
+
+    if n == 0 {
+        return previous;
+    }
+
+    for _ in 1..n {
+        // T_{k + 1} = 2 (2t - 1) T_k - T_{k - 1}
+        let mut next = [T::from_i32(0); N];
+        for k in 0..N {
+            next[k] = -current[k] * T::from_i32(2) - previous[k];
+            if k > 0 {
+                next[k] += current[k - 1] * T::from_i32(4);
+            }
+        }
+        previous = current;
+        current = next;
+    }
+    current
+}
+
+#[cfg(test)]
+mod tests {
+    mod polynomial_curve {
+        use crate::{
+            CubicCurve,
+            PolynomialCurve,
+            QuadraticCurve,
+            Vector2,
+        };
+        use approx::{
+            assert_abs_diff_eq,
+            assert_ulps_eq,
+        };
+
+        #[test]
+        fn evaluate() {
+            let curve = PolynomialCurve::new([1f32, 1f32, 1f32, 1f32, 1f32]);
+            assert_eq!(curve.degree(), 4);
+
+            assert_ulps_eq!(curve.evaluate(0f32), 1f32);
+            assert_ulps_eq!(curve.evaluate_derivative(0f32), 1f32);
+
+            assert_ulps_eq!(curve.evaluate(0.5f32), 1.9375f32);
+            assert_ulps_eq!(curve.evaluate_derivative(0.5f32), 3.25f32);
+
+            assert_ulps_eq!(curve.evaluate(1f32), 5f32);
+            assert_ulps_eq!(curve.evaluate_derivative(1f32), 10f32);
+
+            let derivative = curve.derivative();
+            assert_eq!(derivative.coefficients(), [1f32, 2f32, 3f32, 4f32, 0f32]);
+            assert_eq!(derivative.derivative().coefficients(), [2f32, 6f32, 12f32, 0f32, 0f32]);
+        }
+
+        #[test]
+        fn bezier() {
+            let test_cases = [
+                [1f32, 1f32, 1f32, 1f32, 1f32],
+                [2f32, 0f32, 0f32, 1f32, -1f32],
+                [-1f32, 1f32, 0.5f32, -0.25f32, 3f32],
+                [6f32, -7f32, -9f32, -1000f32, 12f32],
+            ];
+            for test_case in &test_cases {
+                let curve = PolynomialCurve::from_bezier(*test_case);
+                let bezier = curve.to_bezier();
+                for i in 0..5 {
+                    assert_abs_diff_eq!(bezier[i], test_case[i], epsilon = 0.001f32);
+                }
+                assert_ulps_eq!(curve.evaluate(0f32), test_case[0]);
+                assert_abs_diff_eq!(curve.evaluate(1f32), test_case[4], epsilon = 0.001f32);
+            }
+
+            let cubic = CubicCurve::from_bezier(1f32, -1f32, 0.5f32, 4f32);
+            let curve = PolynomialCurve::from_bezier([1f32, -1f32, 0.5f32, 4f32]);
+            assert_eq!(curve, PolynomialCurve::from(cubic));
+        }
+
+        #[test]
+        fn subdivide() {
+            let cubic = CubicCurve::new(1f32, 1f32, 1f32, 1f32);
+            let (cubic_left, cubic_right) = cubic.subdivide(0.25f32);
+            let curve = PolynomialCurve::from(cubic);
+            let (left, right) = curve.subdivide(0.25f32);
+            assert_eq!(CubicCurve::from(left), cubic_left);
+            assert_eq!(CubicCurve::from(right), cubic_right);
+
+            let curve = PolynomialCurve::new([Vector2::new(1f32, -1f32), Vector2::new(2f32, 0f32), Vector2::new(-3f32, 1f32), Vector2::new(0f32, 4f32), Vector2::new(1f32, -2f32), Vector2::new(0.5f32, 0.5f32)]);
+            let (left, right) = curve.subdivide(0.4f32);
+            for i in 0..=10 {
+                let t = i as f32 / 10f32;
+                let expected = curve.evaluate(0.4f32 * t);
+                assert_abs_diff_eq!(left.evaluate(t).x, expected.x, epsilon = 0.0001f32);
+                assert_abs_diff_eq!(left.evaluate(t).y, expected.y, epsilon = 0.0001f32);
+                let expected = curve.evaluate(0.4f32 + 0.6f32 * t);
+                assert_abs_diff_eq!(right.evaluate(t).x, expected.x, epsilon = 0.0001f32);
+                assert_abs_diff_eq!(right.evaluate(t).y, expected.y, epsilon = 0.0001f32);
+            }
+        }
+
+        #[test]
+        fn elevate_degree() {
+            let quadratic = QuadraticCurve::new(1f32, 2f32, 3f32);
+            let curve: PolynomialCurve<f32, 5> = PolynomialCurve::from(quadratic).elevate_degree();
+            assert_eq!(curve.coefficients(), [1f32, 2f32, 3f32, 0f32, 0f32]);
+            assert_eq!(curve.degree(), 4);
+            assert_eq!(CubicCurve::from(PolynomialCurve::from(quadratic).elevate_degree::<4>()), quadratic.to_cubic());
+
+            let bezier = PolynomialCurve::from(quadratic).elevate_degree::<4>().to_bezier();
+            let expected = quadratic.to_cubic().to_bezier();
+            assert_ulps_eq!(bezier[1], expected.1);
+            assert_ulps_eq!(bezier[2], expected.2);
+        }
+
+        #[test]
+        fn reduce_degree() {
+            let cubic = CubicCurve::new(1f32, 1f32, 1f32, 1f32);
+            let expected = cubic.error_minimizing_quadratic_approximation();
+            let quadratic = QuadraticCurve::from(PolynomialCurve::from(cubic).reduce_degree::<3>());
+            assert_ulps_eq!(quadratic.x0(), expected.x0());
+            assert_ulps_eq!(quadratic.x1(), expected.x1());
+            assert_ulps_eq!(quadratic.x2(), expected.x2());
+            assert_ulps_eq!(PolynomialCurve::from(cubic).reduce_degree_error::<3>(), 1f32 / 32f32);
+
+            let quadratic = QuadraticCurve::new(1f32, 1f32, 1f32);
+            let expected = quadratic.error_minimizing_linear_approximation();
+            let linear = PolynomialCurve::from(quadratic).reduce_degree::<2>();
+            assert_ulps_eq!(linear.coefficients()[0], expected.x0());
+            assert_ulps_eq!(linear.coefficients()[1], expected.x1());
+
+            let curve = PolynomialCurve::new([Vector2::new(1f32, -1f32), Vector2::new(2f32, 0f32), Vector2::new(-3f32, 1f32), Vector2::new(0f32, 4f32), Vector2::new(1f32, -2f32), Vector2::new(0.5f32, 0.5f32)]);
+            let reduced = curve.reduce_degree::<4>();
+            let error = curve.reduce_degree_error::<4>();
+            for i in 0..=100 {
+                let t = i as f32 / 100f32;
+                assert!((curve.evaluate(t) - reduced.evaluate(t)).length() <= error + 0.0001f32);
+            }
+
+            let same = curve.reduce_degree::<6>();
+            assert_eq!(same, curve);
+            assert_ulps_eq!(curve.reduce_degree_error::<6>(), 0f32);
+        }
+    }
+}
\ No newline at end of file
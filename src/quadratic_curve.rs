@@ -1,99 +1,111 @@
 use crate::{
     CubicCurve,
     InnerProductSpace,
+    LinearApproximation,
     LinearCurve,
+    PolynomialCurve,
     Scalar,
+    cubic_curve::piece_count,
     roots::solve_cubic,
 };
 
+// A PolynomialCurve of degree two, x0 + x1 t + x2 t^2
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct QuadraticCurve<T: InnerProductSpace> {
-    x0: T,
-    x1: T,
-    x2: T,
+    curve: PolynomialCurve<T, 3>,
 }
 
 impl<T: InnerProductSpace> QuadraticCurve<T> {
     pub fn new(x0: T, x1: T, x2: T) -> QuadraticCurve<T> {
         QuadraticCurve {
-            x0,
-            x1,
-            x2,
+            curve: PolynomialCurve::new([x0, x1, x2]),
         }
     }
 
     pub fn x0(&self) -> T {
-        self.x0
+        self.curve.coefficients()[0]
     }
 
     pub fn x1(&self) -> T {
-        self.x1
+        self.curve.coefficients()[1]
     }
 
     pub fn x2(&self) -> T {
-        self.x2
+        self.curve.coefficients()[2]
     }
 
     pub fn evaluate(&self, t: T::Scalar) -> T {
-        self.x0 + (self.x1 + self.x2 * t) * t
+        self.curve.evaluate(t)
     }
 
     pub fn evaluate_first_derivative(&self, t: T::Scalar) -> T {
-        self.x1 + self.x2 * t * T::Scalar::from_i32(2)
+        self.x1() + self.x2() * t * T::Scalar::from_i32(2)
     }
 
     pub fn evaluate_second_derivative(&self) -> T {
-        self.x2 * T::Scalar::from_i32(2)
+        self.x2() * T::Scalar::from_i32(2)
     }
 
     pub fn from_bezier(b0: T, b1: T, b2: T) -> QuadraticCurve<T> {
-        QuadraticCurve {
-            x0: b0,
-            x1: b0 * T::Scalar::from_i32(-2) + b1 * T::Scalar::from_i32(2),
-            x2: b0 + b1 * T::Scalar::from_i32(-2) + b2,
-        }
+        QuadraticCurve::new(
+            b0,
+            b0 * T::Scalar::from_i32(-2) + b1 * T::Scalar::from_i32(2),
+            b0 + b1 * T::Scalar::from_i32(-2) + b2,
+        )
     }
 
     pub fn to_bezier(&self) -> (T, T, T) {
         (
-            self.x0,
-            self.x0 + self.x1 / T::Scalar::from_i32(2),
-            self.x0 + self.x1 + self.x2,
+            self.x0(),
+            self.x0() + self.x1() / T::Scalar::from_i32(2),
+            self.x0() + self.x1() + self.x2(),
         )
     }
 
-    pub fn subdivide(&self, u: T::Scalar) -> (QuadraticCurve<T>, QuadraticCurve<T>) {
-        let x1u = self.x1 * u;
-        let x2u = self.x2 * u;
-        let x2u2 = x2u * u;
-        let w = T::Scalar::from_i32(1) - u;
+    // A quadratic curve only has room for one of the end derivatives, so the curve starts at p0 with derivative m0
+    // and ends at p1
+    pub fn from_hermite(p0: T, m0: T, p1: T) -> QuadraticCurve<T> {
+        QuadraticCurve::new(
+            p0,
+            m0,
+            p1 - p0 - m0,
+        )
+    }
 
+    // The start, start derivative, and end that from_hermite takes
+    pub fn to_hermite(&self) -> (T, T, T) {
         (
-            QuadraticCurve::new(
-                self.x0,
-                x1u,
-                x2u2,
-            ),
-            QuadraticCurve::new(
-                self.x0 + x1u + x2u2,
-                (self.x1 + x2u * T::Scalar::from_i32(2)) * w,
-                self.x2 * w * w,
-            )
+            self.x0(),
+            self.x1(),
+            self.x0() + self.x1() + self.x2(),
+        )
+    }
+
+    pub fn subdivide(&self, u: T::Scalar) -> (QuadraticCurve<T>, QuadraticCurve<T>) {
+        let (left, right) = self.curve.subdivide(u);
+        (
+            QuadraticCurve {
+                curve: left,
+            },
+            QuadraticCurve {
+                curve: right,
+            },
         )
     }
 
     pub fn to_cubic(&self) -> CubicCurve<T> {
         CubicCurve::new(
-            self.x0,
-            self.x1,
-            self.x2,
+            self.x0(),
+            self.x1(),
+            self.x2(),
             T::zero(),
         )
     }
 
     fn speed_coefficients(&self) -> (T::Scalar, T::Scalar, T::Scalar) {
-        let a = self.x2.inner_product(self.x2) * T::Scalar::from_i32(4);
-        let b = self.x1.inner_product(self.x2) * T::Scalar::from_i32(4);
-        let c = self.x1.inner_product(self.x1);
+        let a = self.x2().inner_product(self.x2()) * T::Scalar::from_i32(4);
+        let b = self.x1().inner_product(self.x2()) * T::Scalar::from_i32(4);
+        let c = self.x1().inner_product(self.x1());
         (c, b, a)
     }
 
@@ -177,13 +189,14 @@ impl<T: InnerProductSpace> QuadraticCurve<T> {
     }
 
     pub fn nearest_point(&self, q: T) -> T::Scalar {
-        let a = self.x2.inner_product(self.x2);
-        let b = self.x1.inner_product(self.x2) * T::Scalar::from_i32(2);
-        let c = (self.x0 - q).inner_product(self.x2) * T::Scalar::from_i32(2) + self.x1.inner_product(self.x1);
-        let d = (self.x0 - q).inner_product(self.x1) * T::Scalar::from_i32(2);
-        let e = (self.x0 - q).inner_product(self.x0 - q);
+        let a = self.x2().inner_product(self.x2());
+        let b = self.x1().inner_product(self.x2()) * T::Scalar::from_i32(2);
+        let c = (self.x0() - q).inner_product(self.x2()) * T::Scalar::from_i32(2) + self.x1().inner_product(self.x1());
+        let d = (self.x0() - q).inner_product(self.x1()) * T::Scalar::from_i32(2);
+        let e = (self.x0() - q).inner_product(self.x0() - q);
 
-        let distance = |t| (e + t * (d + t * (c + t * (b + a * t)))).sqrt();
+        // rounding can make the squared distance slightly negative near the curve
+        let distance = |t| (e + t * (d + t * (c + t * (b + a * t)))).abs().sqrt();
 
         let left_value = T::Scalar::from_i32(0);
         let left_distance = distance(left_value);
@@ -212,17 +225,58 @@ impl<T: InnerProductSpace> QuadraticCurve<T> {
 
     pub fn error_minimizing_linear_approximation(&self) -> LinearCurve<T> {
         LinearCurve::new(
-            self.x0 - self.x2 / T::Scalar::from_i32(8),
-            self.x1 + self.x2,
+            self.x0() - self.x2() / T::Scalar::from_i32(8),
+            self.x1() + self.x2(),
         )
     }
 
     pub fn continuity_preserving_linear_approximation(&self) -> LinearCurve<T> {
         LinearCurve::new(
-            self.x0,
-            self.x1 + self.x2,
+            self.x0(),
+            self.x1() + self.x2(),
         )
     }
+
+    pub fn linear_approximation(&self, approximation: LinearApproximation) -> LinearCurve<T> {
+        match approximation {
+            LinearApproximation::ErrorMinimizing => self.error_minimizing_linear_approximation(),
+            LinearApproximation::ContinuityPreserving => self.continuity_preserving_linear_approximation(),
+        }
+    }
+
+    // Splits the curve into the fewest equal pieces whose linear approximations are within the tolerance, or returns
+    // None if that is more than MAX_PIECES
+    pub fn to_lines(&self, tolerance: T::Scalar, approximation: LinearApproximation) -> Option<Vec<LinearCurve<T>>> {
+        assert!(tolerance > T::Scalar::from_i32(0), "the tolerance must be positive");
+
+        // each of n pieces has a quadratic coefficient of x2 / n^2, so its error is k |x2| / n^2
+        let error = self.x2().inner_product(self.x2()).sqrt() * approximation.error_factor::<T::Scalar>();
+        let count = piece_count((error / tolerance).sqrt())?;
+
+        let scale = T::Scalar::from_i32(1) / T::Scalar::from_i32(count);
+        let lines = (0..count)
+            .map(|i| {
+                let t = T::Scalar::from_i32(i) * scale;
+                let piece = QuadraticCurve::new(self.evaluate(t), self.evaluate_first_derivative(t) * scale, self.x2() * scale * scale);
+                piece.linear_approximation(approximation)
+            })
+            .collect();
+        Some(lines)
+    }
+}
+
+impl<T: InnerProductSpace> From<QuadraticCurve<T>> for PolynomialCurve<T, 3> {
+    fn from(curve: QuadraticCurve<T>) -> Self {
+        curve.curve
+    }
+}
+
+impl<T: InnerProductSpace> From<PolynomialCurve<T, 3>> for QuadraticCurve<T> {
+    fn from(curve: PolynomialCurve<T, 3>) -> Self {
+        QuadraticCurve {
+            curve,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -232,6 +286,7 @@ mod tests {
             vector2::Vector2,
             QuadraticCurve,
             InnerProductSpace,
+            LinearApproximation,
             Scalar,
         };
         use approx::{
@@ -273,6 +328,31 @@ mod tests {
             }
         }
 
+        #[test]
+        fn hermite() {
+            let test_cases = [
+                (1f32, 1f32, 1f32),
+                (2f32, 0f32, 1f32),
+                (-1f32, 1f32, 0.5f32),
+                (1f32, -1f32, -5f32),
+                (6f32, -7f32, -9f32),
+            ];
+            for test_case in &test_cases {
+                let curve = QuadraticCurve::from_hermite(test_case.0, test_case.1, test_case.2);
+                let hermite = curve.to_hermite();
+                assert_ulps_eq!(hermite.0, test_case.0);
+                assert_ulps_eq!(hermite.1, test_case.1);
+                assert_ulps_eq!(hermite.2, test_case.2);
+                assert_eq!(QuadraticCurve::from_hermite(hermite.0, hermite.1, hermite.2), curve);
+            }
+
+            // the end tangents both point at the Bezier control point
+            let curve = QuadraticCurve::from_bezier(0f32, 3f32, 2f32);
+            let (p0, m0, p1) = curve.to_hermite();
+            assert_ulps_eq!(p0 + m0 / 2f32, 3f32);
+            assert_ulps_eq!(p1 - curve.evaluate_first_derivative(1f32) / 2f32, 3f32);
+        }
+
         #[test]
         fn subdivide() {
             let curve = QuadraticCurve::new(1f32, 1f32, 1f32);
@@ -360,6 +440,48 @@ mod tests {
             assert_ulps_eq!(curve.nearest_point(Vector2::new(0f32, 0f32)), 0f32);
             assert_ulps_eq!(curve.nearest_point(Vector2::new(2.5f32, 2f32)), 1f32);
             assert_ulps_eq!(curve.nearest_point(Vector2::new(1f32, 2f32)), 0.5f32);
+
+            // points on the curve, where rounding makes the squared distance slightly negative
+            let curve = QuadraticCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32), Vector2::new(2f32, 0f32));
+            for &t in &[0.12f32, 0.24f32, 0.35f32] {
+                assert!((curve.nearest_point(curve.evaluate(t)) - t).abs() < 0.001f32);
+            }
+        }
+
+        #[test]
+        fn to_lines() {
+            let curve = QuadraticCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32), Vector2::new(3f32, 0f32));
+            let x2 = curve.x2().length();
+            for &approximation in &[LinearApproximation::ErrorMinimizing, LinearApproximation::ContinuityPreserving] {
+                let k = if approximation == LinearApproximation::ErrorMinimizing { 0.125f32 } else { 0.25f32 };
+                for &tolerance in &[0.1f32, 0.01f32, 0.001f32] {
+                    let lines = curve.to_lines(tolerance, approximation).unwrap();
+                    let n = lines.len() as f32;
+
+                    // the count is the fewest equal pieces that meet the tolerance
+                    assert!(k * x2 / (n * n) <= tolerance);
+                    assert!(n == 1f32 || k * x2 / ((n - 1f32) * (n - 1f32)) > tolerance);
+
+                    for (i, line) in lines.iter().enumerate() {
+                        for j in 0..=100 {
+                            let u = j as f32 / 100f32;
+                            let t = (i as f32 + u) / n;
+                            assert!((curve.evaluate(t) - line.evaluate(u)).length() <= tolerance * 1.001f32);
+                        }
+                    }
+
+                    if approximation == LinearApproximation::ContinuityPreserving {
+                        for pair in lines.windows(2) {
+                            let (end, start) = (pair[0].evaluate(1f32), pair[1].evaluate(0f32));
+                            assert_abs_diff_eq!(end.x, start.x, epsilon = 0.00001f32);
+                            assert_abs_diff_eq!(end.y, start.y, epsilon = 0.00001f32);
+                        }
+                    }
+                }
+
+                // tolerances that need too many lines are reported
+                assert!(curve.to_lines(1e-30f32, approximation).is_none());
+            }
         }
     }
 }
\ No newline at end of file
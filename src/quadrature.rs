@@ -0,0 +1,57 @@
+use crate::Scalar;
+
+const MAX_DEPTH: usize = 12;
+
+// Five-point Gauss-Legendre rule on [-1, 1], exact for polynomials up to degree 9
+struct GaussLegendre<T: Scalar> {
+    nodes: [T; 5],
+    weights: [T; 5],
+}
+
+impl<T: Scalar> GaussLegendre<T> {
+    fn new() -> GaussLegendre<T> {
+        let r = (T::from_i32(10) / T::from_i32(7)).sqrt() * T::from_i32(2);
+        let n1 = (T::from_i32(5) - r).sqrt() / T::from_i32(3);
+        let n2 = (T::from_i32(5) + r).sqrt() / T::from_i32(3);
+        let s = T::from_i32(70).sqrt() * T::from_i32(13);
+        let w0 = T::from_i32(128) / T::from_i32(225);
+        let w1 = (T::from_i32(322) + s) / T::from_i32(900);
+        let w2 = (T::from_i32(322) - s) / T::from_i32(900);
+
+        GaussLegendre {
+            nodes: [-n2, -n1, T::from_i32(0), n1, n2],
+            weights: [w2, w1, w0, w1, w2],
+        }
+    }
+
+    fn apply<F: Fn(T) -> T>(&self, f: &F, lo: T, hi: T) -> T {
+        let half_width = (hi - lo) / T::from_i32(2);
+        let center = lo + half_width;
+
+        let mut sum = T::from_i32(0);
+        for i in 0..5 {
+            sum += self.weights[i] * f(center + half_width * self.nodes[i]);
+        }
+        sum * half_width
+    }
+}
+
+pub fn integrate<T: Scalar, F: Fn(T) -> T>(f: F, lo: T, hi: T, tolerance: T) -> T {
+    let rule = GaussLegendre::new();
+    let whole = rule.apply(&f, lo, hi);
+    integrate_adaptive(&rule, &f, lo, hi, whole, tolerance, MAX_DEPTH)
+}
+
+fn integrate_adaptive<T: Scalar, F: Fn(T) -> T>(rule: &GaussLegendre<T>, f: &F, lo: T, hi: T, whole: T, tolerance: T, depth: usize) -> T {
+    let mid = (lo + hi) / T::from_i32(2);
+    let left = rule.apply(f, lo, mid);
+    let right = rule.apply(f, mid, hi);
+    let sum = left + right;
+
+    if depth == 0 || (sum - whole).abs() <= tolerance {
+        sum
+    } else {
+        let half_tolerance = tolerance / T::from_i32(2);
+        integrate_adaptive(rule, f, lo, mid, left, half_tolerance, depth - 1) + integrate_adaptive(rule, f, mid, hi, right, half_tolerance, depth - 1)
+    }
+}
@@ -0,0 +1,322 @@
+use crate::{
+    BezierCurve,
+    InnerProductSpace,
+    QuadraticCurve,
+    Scalar,
+    polynomial_curve::binomial,
+};
+
+const MAX_DEPTH: usize = 16;
+
+// The curve has N control points, and so is of degree N - 1, with a positive weight for each control point
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RationalBezierCurve<T: InnerProductSpace, const N: usize> {
+    points: [T; N],
+    weights: [T::Scalar; N],
+}
+
+impl<T: InnerProductSpace, const N: usize> RationalBezierCurve<T, N> {
+    pub fn new(points: [T; N], weights: [T::Scalar; N]) -> RationalBezierCurve<T, N> {
+        assert!(N > 0, "a curve needs at least one control point");
+        assert!(weights.iter().all(|&weight| weight > T::Scalar::from_i32(0)), "weights must be positive");
+
+        RationalBezierCurve {
+            points,
+            weights,
+        }
+    }
+
+    pub fn points(&self) -> [T; N] {
+        self.points
+    }
+
+    pub fn weights(&self) -> [T::Scalar; N] {
+        self.weights
+    }
+
+    pub fn degree(&self) -> usize {
+        N - 1
+    }
+
+    // The control points multiplied by their weights, which together with the weights form a polynomial curve one
+    // dimension higher
+    fn homogeneous(&self) -> ([T; N], [T::Scalar; N]) {
+        let mut points = self.points;
+        for (point, &weight) in points.iter_mut().zip(self.weights.iter()) {
+            *point *= weight;
+        }
+        (points, self.weights)
+    }
+
+    // Returns the numerator, weight, and their derivatives at t
+    fn evaluate_homogeneous(&self, t: T::Scalar) -> (T, T::Scalar, T, T::Scalar) {
+        let w = T::Scalar::from_i32(1) - t;
+        let (mut points, mut weights) = self.homogeneous();
+        if N < 2 {
+            return (points[0], weights[0], T::zero(), T::Scalar::from_i32(0));
+        }
+
+        // stop one level short of the end of the de Casteljau triangle, the last two points give the tangent
+        for n in (2..N).rev() {
+            for i in 0..n {
+                points[i] = points[i] * w + points[i + 1] * t;
+                weights[i] = weights[i] * w + weights[i + 1] * t;
+            }
+        }
+
+        let degree = T::Scalar::from_i32(N as i32 - 1);
+        (
+            points[0] * w + points[1] * t,
+            weights[0] * w + weights[1] * t,
+            (points[1] - points[0]) * degree,
+            (weights[1] - weights[0]) * degree,
+        )
+    }
+
+    pub fn evaluate(&self, t: T::Scalar) -> T {
+        let (point, weight, _, _) = self.evaluate_homogeneous(t);
+        point / weight
+    }
+
+    pub fn evaluate_derivative(&self, t: T::Scalar) -> T {
+        // the quotient rule
+        let (point, weight, point_derivative, weight_derivative) = self.evaluate_homogeneous(t);
+        (point_derivative - point * (weight_derivative / weight)) / weight
+    }
+
+    pub fn subdivide(&self, u: T::Scalar) -> (RationalBezierCurve<T, N>, RationalBezierCurve<T, N>) {
+        let w = T::Scalar::from_i32(1) - u;
+        let (mut points, mut weights) = self.homogeneous();
+        let mut left = (points, weights);
+        let mut right = (points, weights);
+        for n in (0..N).rev() {
+            left.0[N - 1 - n] = points[0] / weights[0];
+            left.1[N - 1 - n] = weights[0];
+            right.0[n] = points[n] / weights[n];
+            right.1[n] = weights[n];
+            for i in 0..n {
+                points[i] = points[i] * w + points[i + 1] * u;
+                weights[i] = weights[i] * w + weights[i + 1] * u;
+            }
+        }
+
+        (
+            RationalBezierCurve::new(left.0, left.1),
+            RationalBezierCurve::new(right.0, right.1),
+        )
+    }
+
+    // The quadratic curve that passes through the ends and middle of the curve
+    pub fn quadratic_approximation(&self) -> QuadraticCurve<T> {
+        let start = self.points[0];
+        let end = self.points[N - 1];
+        let middle = self.evaluate(T::Scalar::from_i32(1) / T::Scalar::from_i32(2));
+        QuadraticCurve::from_bezier(start, middle * T::Scalar::from_i32(2) - (start + end) / T::Scalar::from_i32(2), end)
+    }
+
+    // An upper bound on the distance between the curve and a quadratic curve at the same parameter
+    //
+    // The difference is (A - w q) / w where A is the weighted numerator. The numerator of that is a polynomial of
+    // degree N + 1 whose Bernstein coefficients bound it, and the weight is never less than the smallest weight.
+    pub fn quadratic_error_bound(&self, quadratic: &QuadraticCurve<T>) -> T::Scalar {
+        let (points, weights) = self.homogeneous();
+        let (q0, q1, q2) = quadratic.to_bezier();
+        let q = [q0, q1, q2];
+        let n = N - 1;
+
+        let mut bound = T::Scalar::from_i32(0);
+        for k in 0..=n + 2 {
+            let mut difference = T::zero();
+            for i in k.saturating_sub(2)..=k.min(n) {
+                // the product of Bernstein polynomials of degrees n and 2 in terms of degree n + 2
+                let scale = binomial::<T::Scalar>(n, i) * binomial(2, k - i) / binomial(n + 2, k);
+                difference += (points[i] - q[k - i] * weights[i]) * scale;
+            }
+            let distance = difference.inner_product(difference).sqrt();
+            if distance > bound {
+                bound = distance;
+            }
+        }
+
+        let mut min_weight = self.weights[0];
+        for &weight in self.weights.iter() {
+            if weight < min_weight {
+                min_weight = weight;
+            }
+        }
+        bound / min_weight
+    }
+
+    // Subdivides in half until each piece is within the tolerance of its quadratic approximation. Returns None if a
+    // piece is still too far from its approximation after being halved MAX_DEPTH times.
+    pub fn to_quadratics(&self, tolerance: T::Scalar) -> Option<Vec<QuadraticCurve<T>>> {
+        assert!(tolerance > T::Scalar::from_i32(0), "the tolerance must be positive");
+
+        let mut quadratics = Vec::new();
+        if self.push_quadratics(tolerance, MAX_DEPTH, &mut quadratics) {
+            Some(quadratics)
+        } else {
+            None
+        }
+    }
+
+    fn push_quadratics(&self, tolerance: T::Scalar, depth: usize, quadratics: &mut Vec<QuadraticCurve<T>>) -> bool {
+        let quadratic = self.quadratic_approximation();
+        if self.quadratic_error_bound(&quadratic) <= tolerance {
+            quadratics.push(quadratic);
+            true
+        } else if depth == 0 {
+            false
+        } else {
+            let (left, right) = self.subdivide(T::Scalar::from_i32(1) / T::Scalar::from_i32(2));
+            left.push_quadratics(tolerance, depth - 1, quadratics) && right.push_quadratics(tolerance, depth - 1, quadratics)
+        }
+    }
+}
+
+impl<T: InnerProductSpace, const N: usize> From<BezierCurve<T, N>> for RationalBezierCurve<T, N> {
+    fn from(curve: BezierCurve<T, N>) -> Self {
+        RationalBezierCurve::new(curve.points(), [T::Scalar::from_i32(1); N])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod rational_bezier_curve {
+        use crate::{
+            BezierCurve,
+            RationalBezierCurve,
+            Vector2,
+            Vector3,
+        };
+        use approx::{
+            assert_abs_diff_eq,
+            assert_ulps_eq,
+        };
+
+        fn quarter_circle() -> RationalBezierCurve<Vector2<f32>, 3> {
+            RationalBezierCurve::new(
+                [Vector2::new(1f32, 0f32), Vector2::new(1f32, 1f32), Vector2::new(0f32, 1f32)],
+                [1f32, 0.5f32.sqrt(), 1f32],
+            )
+        }
+
+        fn cubic() -> RationalBezierCurve<Vector3<f32>, 4> {
+            RationalBezierCurve::new(
+                [Vector3::new(0f32, 0f32, 0f32), Vector3::new(1f32, 2f32, 0f32), Vector3::new(3f32, 2f32, 1f32), Vector3::new(4f32, 0f32, 1f32)],
+                [1f32, 3f32, 0.5f32, 2f32],
+            )
+        }
+
+        #[test]
+        fn evaluate() {
+            let circle = quarter_circle();
+            assert_eq!(circle.evaluate(0f32), Vector2::new(1f32, 0f32));
+            assert_eq!(circle.evaluate(1f32), Vector2::new(0f32, 1f32));
+            for i in 0..=10 {
+                let point = circle.evaluate(i as f32 / 10f32);
+                assert_ulps_eq!(point.length(), 1f32, max_ulps = 4);
+            }
+            assert_ulps_eq!(circle.evaluate(0.5f32).x, 0.5f32.sqrt());
+
+            // unit weights are an ordinary Bezier curve
+            let bezier = BezierCurve::new([Vector2::new(0f32, 0f32), Vector2::new(1f32, 3f32), Vector2::new(2f32, -1f32), Vector2::new(3f32, 0f32)]);
+            let rational = RationalBezierCurve::from(bezier);
+            for i in 0..=10 {
+                let t = i as f32 / 10f32;
+                assert_abs_diff_eq!(rational.evaluate(t).x, bezier.evaluate(t).x, epsilon = 0.00001f32);
+                assert_abs_diff_eq!(rational.evaluate(t).y, bezier.evaluate(t).y, epsilon = 0.00001f32);
+                assert_abs_diff_eq!(rational.evaluate_derivative(t).x, bezier.evaluate_derivative(t).x, epsilon = 0.0001f32);
+                assert_abs_diff_eq!(rational.evaluate_derivative(t).y, bezier.evaluate_derivative(t).y, epsilon = 0.0001f32);
+            }
+        }
+
+        #[test]
+        fn derivative() {
+            let circle = quarter_circle();
+            for i in 0..=10 {
+                let t = i as f32 / 10f32;
+                // the tangent of a circle is perpendicular to the radius
+                let point = circle.evaluate(t);
+                let derivative = circle.evaluate_derivative(t);
+                assert_abs_diff_eq!(point.x * derivative.x + point.y * derivative.y, 0f32, epsilon = 0.00001f32);
+            }
+
+            let cubic = cubic();
+            for i in 1..10 {
+                let t = i as f32 / 10f32;
+                let h = 0.001f32;
+                let difference = (cubic.evaluate(t + h) - cubic.evaluate(t - h)) / (2f32 * h);
+                let derivative = cubic.evaluate_derivative(t);
+                assert_abs_diff_eq!(derivative.x, difference.x, epsilon = 0.01f32);
+                assert_abs_diff_eq!(derivative.y, difference.y, epsilon = 0.01f32);
+                assert_abs_diff_eq!(derivative.z, difference.z, epsilon = 0.01f32);
+            }
+        }
+
+        #[test]
+        fn subdivide() {
+            let cubic = cubic();
+            let (left, right) = cubic.subdivide(0.3f32);
+            for i in 0..=10 {
+                let u = i as f32 / 10f32;
+                let expected = cubic.evaluate(0.3f32 * u);
+                assert_abs_diff_eq!(left.evaluate(u).x, expected.x, epsilon = 0.00001f32);
+                assert_abs_diff_eq!(left.evaluate(u).y, expected.y, epsilon = 0.00001f32);
+                assert_abs_diff_eq!(left.evaluate(u).z, expected.z, epsilon = 0.00001f32);
+                let expected = cubic.evaluate(0.3f32 + 0.7f32 * u);
+                assert_abs_diff_eq!(right.evaluate(u).x, expected.x, epsilon = 0.00001f32);
+                assert_abs_diff_eq!(right.evaluate(u).y, expected.y, epsilon = 0.00001f32);
+                assert_abs_diff_eq!(right.evaluate(u).z, expected.z, epsilon = 0.00001f32);
+            }
+
+            // pieces of a circle are still circles
+            let (left, right) = quarter_circle().subdivide(0.5f32);
+            for i in 0..=10 {
+                let u = i as f32 / 10f32;
+                assert_ulps_eq!(left.evaluate(u).length(), 1f32, max_ulps = 4);
+                assert_ulps_eq!(right.evaluate(u).length(), 1f32, max_ulps = 4);
+            }
+        }
+
+        #[test]
+        fn to_quadratics() {
+            let circle = quarter_circle();
+            let quadratic = circle.quadratic_approximation();
+            let bound = circle.quadratic_error_bound(&quadratic);
+            for i in 0..=100 {
+                let t = i as f32 / 100f32;
+                assert!((circle.evaluate(t) - quadratic.evaluate(t)).length() <= bound);
+            }
+
+            for &tolerance in &[0.01f32, 0.001f32, 0.0001f32] {
+                let quadratics = circle.to_quadratics(tolerance).unwrap();
+                for quadratic in quadratics.iter() {
+                    for i in 0..=20 {
+                        let radius = quadratic.evaluate(i as f32 / 20f32).length();
+                        assert!((radius - 1f32).abs() <= tolerance);
+                    }
+                }
+                for pair in quadratics.windows(2) {
+                    assert_abs_diff_eq!(pair[0].evaluate(1f32).x, pair[1].evaluate(0f32).x, epsilon = 0.00001f32);
+                    assert_abs_diff_eq!(pair[0].evaluate(1f32).y, pair[1].evaluate(0f32).y, epsilon = 0.00001f32);
+                }
+            }
+
+            let cubic = cubic();
+            let quadratics = cubic.to_quadratics(0.001f32).unwrap();
+            assert!(quadratics.len() > 1);
+            let samples = (0..=20000).map(|i| cubic.evaluate(i as f32 / 20000f32)).collect::<Vec<_>>();
+            for quadratic in quadratics.iter() {
+                for j in 0..=10 {
+                    let point = quadratic.evaluate(j as f32 / 10f32);
+                    let distance = samples.iter().map(|&sample| (sample - point).length()).fold(f32::MAX, f32::min);
+                    assert!(distance <= 0.0011f32);
+                }
+            }
+
+            // pieces halved MAX_DEPTH times are still too far from their quadratics
+            assert!(cubic.to_quadratics(1e-30f32).is_none());
+        }
+    }
+}
\ No newline at end of file
@@ -1,23 +1,155 @@
+//! Real root solvers for polynomials.
+//!
+//! Coefficients are always ordered from the highest degree to the lowest, so `solve_cubic(a, b, c, d)` solves
+//! `a x^3 + b x^2 + c x + d = 0`. Leading coefficients of zero are allowed and reduce the degree of the polynomial.
+//!
+//! The unrestricted solvers return roots in no particular order and may contain repeated roots. The `_in` variants only
+//! return roots inside an interval, sorted in increasing order and without duplicates.
+
+use std::{
+    iter::FusedIterator,
+    slice,
+};
 use crate::Scalar;
 
+/// The real roots of a polynomial of degree four or less.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Roots<T: Scalar> {
     None([T; 0]),
     One([T; 1]),
     Two([T; 2]),
     Three([T; 3]),
+    Four([T; 4]),
 }
 
 impl<T: Scalar> Roots<T> {
+    /// Returns the roots as a slice.
     pub fn as_slice(&self) -> &[T] {
         match self {
             Roots::None(s) => &s[..],
             Roots::One(s) => &s[..],
             Roots::Two(s) => &s[..],
             Roots::Three(s) => &s[..],
+            Roots::Four(s) => &s[..],
+        }
+    }
+
+    pub(crate) fn from_slice(roots: &[T]) -> Roots<T> {
+        match *roots {
+            [] => Roots::None([]),
+            [r0] => Roots::One([r0]),
+            [r0, r1] => Roots::Two([r0, r1]),
+            [r0, r1, r2] => Roots::Three([r0, r1, r2]),
+            [r0, r1, r2, r3, ..] => Roots::Four([r0, r1, r2, r3]),
         }
     }
+
+    /// Returns an iterator over the roots.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns the number of roots.
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Returns whether there are no roots.
+    pub fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+
+    pub(crate) fn restrict(&self, lo: T, hi: T) -> Roots<T> {
+        let mut roots = [T::from_i32(0); 4];
+        let mut len = 0;
+        for &root in self.iter() {
+            if root >= lo && root <= hi {
+                // insertion sort, skipping duplicates
+                let mut i = len;
+                while i > 0 && roots[i - 1] > root {
+                    i -= 1;
+                }
+                if i == 0 || roots[i - 1] != root {
+                    for j in (i..len).rev() {
+                        roots[j + 1] = roots[j];
+                    }
+                    roots[i] = root;
+                    len += 1;
+                }
+            }
+        }
+        Roots::from_slice(&roots[..len])
+    }
+}
+
+impl<'a, T: Scalar> IntoIterator for &'a Roots<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
 
+impl<T: Scalar> IntoIterator for Roots<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let mut roots = [T::from_i32(0); MAX_DEGREE];
+        let len = self.len();
+        roots[..len].copy_from_slice(self.as_slice());
+        IntoIter {
+            roots,
+            index: 0,
+            len,
+        }
+    }
+}
+
+/// An owning iterator over the roots in a [`Roots`] or [`PolynomialRoots`].
+#[derive(Clone, Debug)]
+pub struct IntoIter<T: Scalar> {
+    roots: [T; MAX_DEGREE],
+    index: usize,
+    len: usize,
+}
+
+impl<T: Scalar> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < self.len {
+            let root = self.roots[self.index];
+            self.index += 1;
+            Some(root)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: Scalar> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index < self.len {
+            self.len -= 1;
+            Some(self.roots[self.len])
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Scalar> ExactSizeIterator for IntoIter<T> {}
+
+impl<T: Scalar> FusedIterator for IntoIter<T> {}
+
+/// Solves `a x + b = 0`.
 pub fn solve_linear<T: Scalar>(a: T, b: T) -> Roots<T> {
     if a == T::from_i32(0) {
         Roots::None([])
@@ -26,18 +158,44 @@ pub fn solve_linear<T: Scalar>(a: T, b: T) -> Roots<T> {
     }
 }
 
+/// Solves `a x^2 + b x + c = 0`.
+///
+/// A double root is returned once. The roots are calculated in a way that avoids catastrophic cancellation.
 pub fn solve_quadratic<T: Scalar>(a: T, b: T, c: T) -> Roots<T> {
+    solve_quadratic_with_tolerance(a, b, c, T::from_i32(0))
+}
+
+/// Solves `a x^2 + b x + c = 0`, treating discriminants that are within `tolerance` relative to the magnitude of its terms
+/// as zero.
+///
+/// This recovers double roots that are lost to rounding error, e.g. for `(x - 0.1)^2`.
+pub fn solve_quadratic_with_tolerance<T: Scalar>(a: T, b: T, c: T, tolerance: T) -> Roots<T> {
     if a == T::from_i32(0) {
         solve_linear(b, c)
     } else {
-        let a2 = a * T::from_i32(2);
-        let sqrtb2m4ac = (b * b - a * c * T::from_i32(4)).sqrt();
-        let r0 = (-b + sqrtb2m4ac) / a2;
-        let r1 = (-b - sqrtb2m4ac) / a2;
-        Roots::Two([r0, r1])
+        let b2 = b * b;
+        let ac4 = a * c * T::from_i32(4);
+        let discriminant = b2 - ac4;
+        let scale = if b2 > ac4.abs() { b2 } else { ac4.abs() };
+
+        if discriminant.abs() <= tolerance * scale {
+            Roots::One([-b / (a * T::from_i32(2))])
+        } else if discriminant < T::from_i32(0) {
+            Roots::None([])
+        } else {
+            // avoid cancellation by never subtracting values with the same sign
+            let sqrtd = discriminant.sqrt();
+            let q = if b >= T::from_i32(0) {
+                -(b + sqrtd) / T::from_i32(2)
+            } else {
+                (sqrtd - b) / T::from_i32(2)
+            };
+            Roots::Two([q / a, c / q])
+        }
     }
 }
 
+/// Solves `a x^3 + b x^2 + c x + d = 0` in closed form.
 pub fn solve_cubic<T: Scalar>(a: T, b: T, c: T, d: T) -> Roots<T> {
     if a == T::from_i32(0) {
         solve_quadratic(b, c, d)
@@ -75,4 +233,409 @@ pub fn solve_cubic<T: Scalar>(a: T, b: T, c: T, d: T) -> Roots<T> {
             Roots::Three([r1, r2, r3])
         }
     }
+}
+
+/// Solves `a x^4 + b x^3 + c x^2 + d x + e = 0`.
+///
+/// The roots are isolated and refined with [`solve_polynomial`] over an interval that bounds every real root.
+pub fn solve_quartic<T: Scalar>(a: T, b: T, c: T, d: T, e: T) -> Roots<T> {
+    if a == T::from_i32(0) {
+        solve_cubic(b, c, d, e)
+    } else {
+        // every real root lies within the Cauchy bound
+        let mut bound = T::from_i32(0);
+        for &coefficient in [b, c, d, e].iter() {
+            let ratio = (coefficient / a).abs();
+            if ratio > bound {
+                bound = ratio;
+            }
+        }
+        bound += T::from_i32(1);
+
+        Roots::from_slice(isolate_roots(&[a, b, c, d, e], (-bound, bound)).as_slice())
+    }
+}
+
+/// Solves `a x + b = 0` for roots between `lo` and `hi` inclusive.
+pub fn solve_linear_in<T: Scalar>(a: T, b: T, lo: T, hi: T) -> Roots<T> {
+    solve_linear(a, b).restrict(lo, hi)
+}
+
+/// Solves `a x^2 + b x + c = 0` for roots between `lo` and `hi` inclusive, sorted and without duplicates.
+pub fn solve_quadratic_in<T: Scalar>(a: T, b: T, c: T, lo: T, hi: T) -> Roots<T> {
+    solve_quadratic(a, b, c).restrict(lo, hi)
+}
+
+/// Solves `a x^3 + b x^2 + c x + d = 0` for roots between `lo` and `hi` inclusive, sorted and without duplicates.
+pub fn solve_cubic_in<T: Scalar>(a: T, b: T, c: T, d: T, lo: T, hi: T) -> Roots<T> {
+    solve_cubic(a, b, c, d).restrict(lo, hi)
+}
+
+/// Solves `a x^4 + b x^3 + c x^2 + d x + e = 0` for roots between `lo` and `hi` inclusive, sorted and without
+/// duplicates.
+pub fn solve_quartic_in<T: Scalar>(a: T, b: T, c: T, d: T, e: T, lo: T, hi: T) -> Roots<T> {
+    Roots::from_slice(isolate_roots(&[a, b, c, d, e], (lo, hi)).as_slice())
+}
+
+/// The highest degree polynomial supported by [`solve_polynomial`].
+///
+/// This is seven, which is enough for the extrema of the curvature of a cubic curve.
+pub const MAX_DEGREE: usize = 7;
+
+/// The real roots of a polynomial of degree [`MAX_DEGREE`] or less, sorted and without duplicates.
+#[derive(Clone, Copy, Debug)]
+pub struct PolynomialRoots<T: Scalar> {
+    roots: [T; MAX_DEGREE],
+    len: usize,
+}
+
+impl<T: Scalar> PolynomialRoots<T> {
+    fn new() -> PolynomialRoots<T> {
+        PolynomialRoots {
+            roots: [T::from_i32(0); MAX_DEGREE],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, root: T) {
+        if self.len < MAX_DEGREE && (self.len == 0 || self.roots[self.len - 1] != root) {
+            self.roots[self.len] = root;
+            self.len += 1;
+        }
+    }
+
+    /// Returns the roots as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.roots[..self.len]
+    }
+
+    /// Returns an iterator over the roots.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns the number of roots.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether there are no roots.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<'a, T: Scalar> IntoIterator for &'a PolynomialRoots<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Scalar> IntoIterator for PolynomialRoots<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            roots: self.roots,
+            index: 0,
+            len: self.len,
+        }
+    }
+}
+
+fn evaluate_polynomial<T: Scalar>(coefficients: &[T], x: T) -> T {
+    let mut result = T::from_i32(0);
+    for &coefficient in coefficients.iter() {
+        result = result * x + coefficient;
+    }
+    result
+}
+
+fn bisect<T: Scalar>(coefficients: &[T], mut lo: T, mut hi: T, lo_value: T) -> T {
+    let lo_positive = lo_value > T::from_i32(0);
+    loop {
+        let mid = (lo + hi) / T::from_i32(2);
+        if mid <= lo || mid >= hi {
+            // the interval can't be split any further
+            return mid;
+        }
+
+        let mid_value = evaluate_polynomial(coefficients, mid);
+        if mid_value == T::from_i32(0) {
+            return mid;
+        } else if (mid_value > T::from_i32(0)) == lo_positive {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+}
+
+// The smallest power of two that changes the value of one when added to it
+fn epsilon<T: Scalar>() -> T {
+    let one = T::from_i32(1);
+    let two = T::from_i32(2);
+    let mut epsilon = one;
+    while one + epsilon / two > one {
+        epsilon /= two;
+    }
+    epsilon
+}
+
+// A bound on the rounding error of evaluating the polynomial at x with Horner's method
+fn rounding_error<T: Scalar>(coefficients: &[T], x: T) -> T {
+    let mut magnitude = T::from_i32(0);
+    for &coefficient in coefficients.iter() {
+        magnitude = magnitude * x.abs() + coefficient.abs();
+    }
+    T::from_i32(4 * coefficients.len() as i32) * epsilon::<T>() * magnitude
+}
+
+/// Solves the polynomial with the given coefficients for roots between `interval.0` and `interval.1` inclusive.
+///
+/// Every root where the polynomial changes sign is found. Roots are isolated recursively using the roots of the
+/// derivative and then refined by bisection. A root of even multiplicity is found where the polynomial has a local
+/// extremum that is within rounding error of zero, so nearby roots that rounding can't separate are reported once.
+///
+/// Returns `None` for polynomials of degree greater than [`MAX_DEGREE`].
+pub fn solve_polynomial<T: Scalar>(coefficients: &[T], interval: (T, T)) -> Option<PolynomialRoots<T>> {
+    let leading_zeroes = coefficients.iter().take_while(|&&c| c == T::from_i32(0)).count();
+    if coefficients.len() - leading_zeroes > MAX_DEGREE + 1 {
+        None
+    } else {
+        Some(isolate_roots(coefficients, interval))
+    }
+}
+
+// Solves a polynomial of degree MAX_DEGREE or less
+fn isolate_roots<T: Scalar>(coefficients: &[T], interval: (T, T)) -> PolynomialRoots<T> {
+    let (lo, hi) = interval;
+    let mut result = PolynomialRoots::new();
+
+    let first_nonzero = coefficients.iter().position(|&c| c != T::from_i32(0)).unwrap_or(coefficients.len());
+    let coefficients = &coefficients[first_nonzero..];
+    if coefficients.len() < 2 || hi < lo {
+        return result;
+    }
+
+    // the roots of the derivative split the interval into pieces where the polynomial is monotonic
+    let degree = coefficients.len() - 1;
+    let mut derivative = [T::from_i32(0); MAX_DEGREE];
+    for (i, &coefficient) in coefficients[..degree].iter().enumerate() {
+        derivative[i] = coefficient * T::from_i32((degree - i) as i32);
+    }
+    let critical_points = isolate_roots(&derivative[..degree], interval);
+
+    let mut left = lo;
+    let mut left_value = evaluate_polynomial(coefficients, left);
+    for &right in critical_points.iter().chain(Some(hi).iter()) {
+        let mut right_value = evaluate_polynomial(coefficients, right);
+        if right != hi && right_value.abs() <= rounding_error(coefficients, right) {
+            // a local extremum this close to zero touches it
+            right_value = T::from_i32(0);
+        }
+
+        if left_value == T::from_i32(0) {
+            result.push(left);
+        } else if right_value != T::from_i32(0) && (left_value > T::from_i32(0)) != (right_value > T::from_i32(0)) {
+            result.push(bisect(coefficients, left, right, left_value));
+        }
+        left = right;
+        left_value = right_value;
+    }
+    if left_value == T::from_i32(0) {
+        result.push(left);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    mod roots {
+        use crate::roots::{
+            solve_cubic,
+            solve_cubic_in,
+            solve_linear_in,
+            solve_polynomial,
+            solve_quadratic,
+            solve_quadratic_in,
+            solve_quadratic_with_tolerance,
+            solve_quartic,
+            solve_quartic_in,
+        };
+        use approx::assert_abs_diff_eq;
+
+        fn assert_roots_eq(roots: &[f64], expected: &[f64]) {
+            let mut roots = roots.to_vec();
+            roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            assert_eq!(roots.len(), expected.len());
+            for (&root, &expected) in roots.iter().zip(expected.iter()) {
+                assert_abs_diff_eq!(root, expected, epsilon = 1e-9);
+            }
+        }
+
+        #[test]
+        fn quadratic() {
+            // (x - 1)(x - 2)
+            assert_roots_eq(solve_quadratic(1f64, -3f64, 2f64).as_slice(), &[1f64, 2f64]);
+            // 2(x + 1)(x - 1)
+            assert_roots_eq(solve_quadratic(2f64, 0f64, -2f64).as_slice(), &[-1f64, 1f64]);
+            // x^2 + 1
+            assert_roots_eq(solve_quadratic(1f64, 0f64, 1f64).as_slice(), &[]);
+            // (x - 1)^2
+            assert_roots_eq(solve_quadratic(1f64, -2f64, 1f64).as_slice(), &[1f64]);
+            // x^2
+            assert_roots_eq(solve_quadratic(1f64, 0f64, 0f64).as_slice(), &[0f64]);
+            // x(x - 1)
+            assert_roots_eq(solve_quadratic(1f64, -1f64, 0f64).as_slice(), &[0f64, 1f64]);
+            // 2x - 1
+            assert_roots_eq(solve_quadratic(0f64, 2f64, -1f64).as_slice(), &[0.5f64]);
+            assert_roots_eq(solve_quadratic(0f64, 0f64, 1f64).as_slice(), &[]);
+        }
+
+        #[test]
+        fn quadratic_near_degenerate() {
+            // the textbook formula loses every digit of the small root to cancellation
+            let roots = solve_quadratic(1f64, 1e8f64, 1f64);
+            let mut roots = roots.as_slice().to_vec();
+            roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            assert_eq!(roots.len(), 2);
+            assert_abs_diff_eq!(roots[0], -1e8f64, epsilon = 1e-6);
+            assert_abs_diff_eq!(roots[1] / -1e-8f64, 1f64, epsilon = 1e-12);
+
+            let roots = solve_quadratic(1f64, -1e8f64, 1f64);
+            let mut roots = roots.as_slice().to_vec();
+            roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            assert_eq!(roots.len(), 2);
+            assert_abs_diff_eq!(roots[0] / 1e-8f64, 1f64, epsilon = 1e-12);
+            assert_abs_diff_eq!(roots[1], 1e8f64, epsilon = 1e-6);
+
+            // (x - 1)(x - 1 - 1e-7), and a nearby polynomial with no real roots
+            let (a, b, c) = (1f64, -2f64 - 1e-7f64, 1f64 + 1e-7f64);
+            assert_eq!(solve_quadratic(a, b, c).as_slice().len(), 2);
+            assert_roots_eq(solve_quadratic_with_tolerance(a, b, c, 1e-12f64).as_slice(), &[1f64 + 0.5e-7f64]);
+            assert_eq!(solve_quadratic(a, -2f64, 1f64 + 1e-15f64).as_slice().len(), 0);
+            assert_roots_eq(solve_quadratic_with_tolerance(a, -2f64, 1f64 + 1e-15f64, 1e-12f64).as_slice(), &[1f64]);
+
+            // (x - 0.1)^2 can't be represented exactly, but the tolerance recovers the double root
+            assert_roots_eq(solve_quadratic_with_tolerance(1f64, -0.2f64, 0.01f64, 1e-12f64).as_slice(), &[0.1f64]);
+        }
+
+        #[test]
+        fn interval() {
+            assert_eq!(solve_linear_in(2f64, -1f64, 0f64, 1f64).as_slice(), &[0.5f64]);
+            assert_eq!(solve_linear_in(2f64, -1f64, 0.75f64, 1f64).as_slice(), &[]);
+
+            // (x - 2)(x + 1)
+            assert_eq!(solve_quadratic_in(1f64, -1f64, -2f64, -1f64, 2f64).as_slice(), &[-1f64, 2f64]);
+            assert_eq!(solve_quadratic_in(1f64, -1f64, -2f64, 0f64, 3f64).as_slice(), &[2f64]);
+
+            // (x - 3)(x - 2)(x - 1)
+            assert_roots_eq(solve_cubic_in(1f64, -6f64, 11f64, -6f64, 0f64, 4f64).as_slice(), &[1f64, 2f64, 3f64]);
+            assert_roots_eq(solve_cubic_in(1f64, -6f64, 11f64, -6f64, 1.5f64, 4f64).as_slice(), &[2f64, 3f64]);
+            assert_roots_eq(solve_cubic_in(1f64, -6f64, 11f64, -6f64, 3.5f64, 4f64).as_slice(), &[]);
+
+            // x^3 has a triple root which is only returned once
+            assert_eq!(solve_cubic(1f64, 0f64, 0f64, 0f64).as_slice(), &[0f64, 0f64]);
+            assert_eq!(solve_cubic_in(1f64, 0f64, 0f64, 0f64, -1f64, 1f64).as_slice(), &[0f64]);
+
+            // (x + 2)(x + 1)(x - 1)(x - 3)
+            assert_roots_eq(solve_quartic_in(1f64, -1f64, -7f64, 1f64, 6f64, -1.5f64, 1.5f64).as_slice(), &[-1f64, 1f64]);
+
+            // the results of the interval solvers are sorted
+            let roots = solve_cubic_in(1f64, -6f64, 11f64, -6f64, 0f64, 4f64);
+            for window in roots.as_slice().windows(2) {
+                assert!(window[0] < window[1]);
+            }
+        }
+
+        #[test]
+        fn iterate() {
+            let roots = solve_quadratic_in(1f64, -1f64, -2f64, -1f64, 2f64);
+            assert_eq!(roots.len(), 2);
+            assert!(!roots.is_empty());
+            assert_eq!(roots.iter().copied().collect::<Vec<_>>(), vec![-1f64, 2f64]);
+            assert_eq!((&roots).into_iter().copied().collect::<Vec<_>>(), vec![-1f64, 2f64]);
+            assert_eq!(roots.into_iter().rev().collect::<Vec<_>>(), vec![2f64, -1f64]);
+
+            let mut sum = 0f64;
+            for root in solve_quadratic(1f64, -1f64, -2f64) {
+                sum += root;
+            }
+            assert_abs_diff_eq!(sum, 1f64);
+
+            let coefficients = [1f64, 0f64, 0f64, 0f64, -1f64, 0f64];
+            let roots = solve_polynomial(&coefficients, (-1f64, 1f64)).unwrap();
+            assert_eq!(roots.into_iter().len(), 3);
+            assert_eq!(roots.into_iter().collect::<Vec<_>>(), vec![-1f64, 0f64, 1f64]);
+            assert!(solve_quadratic(1f64, 0f64, 1f64).is_empty());
+        }
+
+        #[test]
+        fn quartic() {
+            // (x + 2)(x + 1)(x - 1)(x - 3)
+            assert_roots_eq(solve_quartic(1f64, -1f64, -7f64, 1f64, 6f64).as_slice(), &[-2f64, -1f64, 1f64, 3f64]);
+            // (x^2 + 1)(x - 2)(x - 0.5)
+            assert_roots_eq(solve_quartic(1f64, -2.5f64, 2f64, -2.5f64, 1f64).as_slice(), &[0.5f64, 2f64]);
+            // x^4 + 1
+            assert_roots_eq(solve_quartic(1f64, 0f64, 0f64, 0f64, 1f64).as_slice(), &[]);
+            // (x - 1)^2 (x + 1)^2
+            assert_roots_eq(solve_quartic(1f64, 0f64, -2f64, 0f64, 1f64).as_slice(), &[-1f64, 1f64]);
+            // 2(x - 1)(x - 2)(x - 3)
+            assert_roots_eq(solve_quartic(0f64, 2f64, -12f64, 22f64, -12f64).as_slice(), &[1f64, 2f64, 3f64]);
+        }
+
+        #[test]
+        fn polynomial() {
+            // (x + 0.5)(x - 0.1)(x - 0.2)(x - 0.3)(x - 0.4)(x - 2)
+            let coefficients = [1f64, -2.5f64, 0.85f64, 0.425f64, -0.2726f64, 0.0464f64, -0.0024f64];
+            assert_roots_eq(solve_polynomial(&coefficients, (0f64, 1f64)).unwrap().as_slice(), &[0.1f64, 0.2f64, 0.3f64, 0.4f64]);
+            assert_roots_eq(solve_polynomial(&coefficients, (-1f64, 3f64)).unwrap().as_slice(), &[-0.5f64, 0.1f64, 0.2f64, 0.3f64, 0.4f64, 2f64]);
+            assert_roots_eq(solve_polynomial(&coefficients, (0.25f64, 0.35f64)).unwrap().as_slice(), &[0.3f64]);
+
+            // x^5 - x, with roots on the ends of the interval
+            let coefficients = [1f64, 0f64, 0f64, 0f64, -1f64, 0f64];
+            assert_roots_eq(solve_polynomial(&coefficients, (0f64, 1f64)).unwrap().as_slice(), &[0f64, 1f64]);
+
+            // leading zeroes are ignored
+            let coefficients = [0f64, 0f64, 1f64, -1f64];
+            assert_roots_eq(solve_polynomial(&coefficients, (-10f64, 10f64)).unwrap().as_slice(), &[1f64]);
+
+            let coefficients = [0f64, 0f64, 0f64];
+            assert_roots_eq(solve_polynomial(&coefficients, (-10f64, 10f64)).unwrap().as_slice(), &[]);
+
+            // polynomials of too high a degree are not supported, but leading zeroes don't count towards the degree
+            let coefficients = [1f64, 0f64, 0f64, 0f64, 0f64, 0f64, 0f64, 0f64, -1f64];
+            assert!(solve_polynomial(&coefficients, (-10f64, 10f64)).is_none());
+            let coefficients = [0f64, 1f64, 0f64, 0f64, 0f64, 0f64, 0f64, 0f64, -1f64];
+            assert_roots_eq(solve_polynomial(&coefficients, (-10f64, 10f64)).unwrap().as_slice(), &[1f64]);
+        }
+
+        #[test]
+        fn double_roots() {
+            // (x^2 - 0.01)^2, whose double roots are not exactly representable
+            let coefficients = [1f64, 0f64, -0.02f64, 0f64, 0.0001f64];
+            assert_roots_eq(solve_polynomial(&coefficients, (-1f64, 1f64)).unwrap().as_slice(), &[-0.1f64, 0.1f64]);
+            assert_roots_eq(solve_quartic(1f64, 0f64, -0.02f64, 0f64, 0.0001f64).as_slice(), &[-0.1f64, 0.1f64]);
+
+            // (x - 0.3)^2 (x - 0.7)^2
+            assert_roots_eq(solve_quartic_in(1f64, -2f64, 1.42f64, -0.42f64, 0.0441f64, 0f64, 1f64).as_slice(), &[0.3f64, 0.7f64]);
+
+            // (x - 0.1)^2 (x + 0.2)(x - 0.5)
+            let coefficients = [1f64, -0.5f64, -0.03f64, 0.017f64, -0.001f64];
+            assert_roots_eq(solve_polynomial(&coefficients, (-1f64, 1f64)).unwrap().as_slice(), &[-0.2f64, 0.1f64, 0.5f64]);
+
+            // (x - 0.1)^2 (x - 0.2)^2 (x - 0.3)^2, all of which are local minima
+            let coefficients = [1f64, -1.2f64, 0.58f64, -0.144f64, 0.0193f64, -0.00132f64, 0.000036f64];
+            assert_roots_eq(solve_polynomial(&coefficients, (0f64, 1f64)).unwrap().as_slice(), &[0.1f64, 0.2f64, 0.3f64]);
+
+            // x^2 + 1 stays well away from zero
+            assert_roots_eq(solve_quartic(0f64, 0f64, 1f64, 0f64, 1f64).as_slice(), &[]);
+        }
+    }
 }
\ No newline at end of file
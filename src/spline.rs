@@ -0,0 +1,276 @@
+use crate::{
+    Curve,
+    InnerProductSpace,
+    Scalar,
+    VectorSpace,
+};
+
+// Segment i of the spline covers the global parameter range [knots[i], knots[i + 1]], which is [i, i + 1] unless other
+// knots are given
+#[derive(Clone, Debug, PartialEq)]
+pub struct Spline<C: Curve> {
+    segments: Vec<C>,
+    knots: Vec<<C::Vector as VectorSpace>::Scalar>,
+}
+
+impl<C: Curve> Spline<C> {
+    pub fn new(segments: Vec<C>) -> Spline<C> {
+        let knots = (0..=segments.len()).map(|i| <C::Vector as VectorSpace>::Scalar::from_i32(i as i32)).collect();
+        Spline {
+            segments,
+            knots,
+        }
+    }
+
+    pub fn with_knots(segments: Vec<C>, knots: Vec<<C::Vector as VectorSpace>::Scalar>) -> Spline<C> {
+        assert_eq!(knots.len(), segments.len() + 1, "there must be one more knot than segments");
+        assert!(knots.windows(2).all(|pair| pair[0] < pair[1]), "knots must be strictly increasing");
+
+        Spline {
+            segments,
+            knots,
+        }
+    }
+
+    pub fn segments(&self) -> &[C] {
+        &self.segments
+    }
+
+    pub fn knots(&self) -> &[<C::Vector as VectorSpace>::Scalar] {
+        &self.knots
+    }
+
+    // The new segment covers a global parameter range of length one after the end of the spline
+    pub fn push(&mut self, segment: C) {
+        let last = self.knots[self.knots.len() - 1];
+        self.segments.push(segment);
+        self.knots.push(last + <C::Vector as VectorSpace>::Scalar::from_i32(1));
+    }
+
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    // Maps a global parameter to a segment index and the parameter within that segment, clamping to the ends of the spline,
+    // and returns None if the spline has no segments
+    pub fn locate(&self, t: <C::Vector as VectorSpace>::Scalar) -> Option<(usize, <C::Vector as VectorSpace>::Scalar)> {
+        if self.segments.is_empty() {
+            return None;
+        }
+
+        // the number of knots between the ends of the spline that are at or before t
+        let index = self.knots[1..self.segments.len()].partition_point(|&knot| knot <= t);
+        let local = (t - self.knots[index]) / self.width(index);
+
+        let zero = <C::Vector as VectorSpace>::Scalar::from_i32(0);
+        let one = <C::Vector as VectorSpace>::Scalar::from_i32(1);
+        if local < zero {
+            Some((index, zero))
+        } else if local > one {
+            Some((index, one))
+        } else {
+            Some((index, local))
+        }
+    }
+
+    fn width(&self, index: usize) -> <C::Vector as VectorSpace>::Scalar {
+        self.knots[index + 1] - self.knots[index]
+    }
+
+    // Maps a segment index and the parameter within that segment to a global parameter
+    fn globalize(&self, index: usize, local: <C::Vector as VectorSpace>::Scalar) -> <C::Vector as VectorSpace>::Scalar {
+        self.knots[index] + self.width(index) * local
+    }
+
+    pub fn evaluate(&self, t: <C::Vector as VectorSpace>::Scalar) -> Option<C::Vector> {
+        self.locate(t).map(|(index, local)| self.segments[index].evaluate(local))
+    }
+
+    pub fn evaluate_derivative(&self, t: <C::Vector as VectorSpace>::Scalar) -> Option<C::Vector> {
+        self.locate(t).map(|(index, local)| self.segments[index].evaluate_derivative(local) / self.width(index))
+    }
+
+    pub fn length(&self, tolerance: <C::Vector as VectorSpace>::Scalar) -> <C::Vector as VectorSpace>::Scalar {
+        let one = <C::Vector as VectorSpace>::Scalar::from_i32(1);
+        let mut length = <C::Vector as VectorSpace>::Scalar::from_i32(0);
+        for segment in self.segments.iter() {
+            length += segment.length(one, tolerance);
+        }
+        length
+    }
+
+    // Clamps to the ends of the spline, and returns None if the spline has a length of zero
+    pub fn parameterize(&self, v: <C::Vector as VectorSpace>::Scalar, tolerance: <C::Vector as VectorSpace>::Scalar, max_iters: usize) -> Option<<C::Vector as VectorSpace>::Scalar> {
+        let zero = <C::Vector as VectorSpace>::Scalar::from_i32(0);
+        let one = <C::Vector as VectorSpace>::Scalar::from_i32(1);
+
+        let mut start = zero;
+        let mut last = None;
+        for (index, segment) in self.segments.iter().enumerate() {
+            let length = segment.length(one, tolerance);
+            if length > zero {
+                // segments with a length of zero can never contain the result
+                last = Some((index, start));
+                if v <= start + length {
+                    break;
+                }
+            }
+            start += length;
+        }
+
+        last.and_then(|(index, start)| {
+            self.segments[index]
+                .parameterize(v - start, tolerance, max_iters)
+                .map(|u| self.globalize(index, u))
+        })
+    }
+
+    // Returns None if the spline has no segments
+    pub fn nearest_point(&self, q: C::Vector) -> Option<<C::Vector as VectorSpace>::Scalar> {
+        let mut best = None;
+        for (index, segment) in self.segments.iter().enumerate() {
+            let u = segment.nearest_point(q);
+            let offset = segment.evaluate(u) - q;
+            let distance = offset.inner_product(offset);
+            let closer = match best {
+                Some((_, best_distance)) => distance < best_distance,
+                None => true,
+            };
+            if closer {
+                best = Some((self.globalize(index, u), distance));
+            }
+        }
+        best.map(|(value, _)| value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod spline {
+        use crate::{
+            CubicCurve,
+            LinearCurve,
+            QuadraticCurve,
+            Spline,
+            Vector2,
+        };
+        use approx::{
+            assert_abs_diff_eq,
+            assert_ulps_eq,
+        };
+
+        fn square() -> Spline<LinearCurve<Vector2<f32>>> {
+            Spline::new(vec![
+                LinearCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 0f32)),
+                LinearCurve::from_bezier(Vector2::new(1f32, 0f32), Vector2::new(1f32, 1f32)),
+                LinearCurve::from_bezier(Vector2::new(1f32, 1f32), Vector2::new(1f32, 1f32)),
+                LinearCurve::from_bezier(Vector2::new(1f32, 1f32), Vector2::new(0f32, 1f32)),
+            ])
+        }
+
+        #[test]
+        fn locate() {
+            let spline = square();
+            assert_eq!(spline.len(), 4);
+            assert_eq!(spline.locate(-1f32).unwrap(), (0, 0f32));
+            assert_eq!(spline.locate(0f32).unwrap(), (0, 0f32));
+            assert_eq!(spline.locate(0.5f32).unwrap(), (0, 0.5f32));
+            assert_eq!(spline.locate(1f32).unwrap(), (1, 0f32));
+            assert_eq!(spline.locate(3.25f32).unwrap(), (3, 0.25f32));
+            assert_eq!(spline.locate(4f32).unwrap(), (3, 1f32));
+            assert_eq!(spline.locate(5f32).unwrap(), (3, 1f32));
+        }
+
+        #[test]
+        fn knots() {
+            let segments = square().segments().to_vec();
+            let spline = Spline::with_knots(segments, vec![0f32, 2f32, 3f32, 3.5f32, 5.5f32]);
+            assert_eq!(spline.locate(1f32).unwrap(), (0, 0.5f32));
+            assert_eq!(spline.locate(3.25f32).unwrap(), (2, 0.5f32));
+            assert_eq!(spline.locate(6f32).unwrap(), (3, 1f32));
+            assert_eq!(spline.evaluate(2.5f32).unwrap(), Vector2::new(1f32, 0.5f32));
+            assert_eq!(spline.evaluate_derivative(1f32).unwrap(), Vector2::new(0.5f32, 0f32));
+            assert_eq!(spline.parameterize(1.5f32, 0.0001f32, 9), Some(2.5f32));
+            assert_ulps_eq!(spline.nearest_point(Vector2::new(0.5f32, 0.9f32)).unwrap(), 4.5f32);
+
+            let mut spline = spline;
+            spline.push(LinearCurve::from_bezier(Vector2::new(0f32, 1f32), Vector2::new(0f32, 0f32)));
+            assert_eq!(spline.knots(), &[0f32, 2f32, 3f32, 3.5f32, 5.5f32, 6.5f32]);
+        }
+
+        #[test]
+        fn empty() {
+            let spline = Spline::<LinearCurve<Vector2<f32>>>::new(Vec::new());
+            assert!(spline.is_empty());
+            assert_eq!(spline.locate(0f32), None);
+            assert_eq!(spline.evaluate(0f32), None);
+            assert_eq!(spline.evaluate_derivative(0f32), None);
+            assert_eq!(spline.parameterize(0f32, 0.0001f32, 16), None);
+            assert_eq!(spline.nearest_point(Vector2::new(0f32, 0f32)), None);
+            assert_ulps_eq!(spline.length(0.0001f32), 0f32);
+        }
+
+        #[test]
+        fn evaluate() {
+            let spline = square();
+            assert_eq!(spline.evaluate(0.5f32).unwrap(), Vector2::new(0.5f32, 0f32));
+            assert_eq!(spline.evaluate(1.5f32).unwrap(), Vector2::new(1f32, 0.5f32));
+            assert_eq!(spline.evaluate(4f32).unwrap(), Vector2::new(0f32, 1f32));
+            assert_eq!(spline.evaluate_derivative(1.5f32).unwrap(), Vector2::new(0f32, 1f32));
+            assert_eq!(spline.evaluate_derivative(3.5f32).unwrap(), Vector2::new(-1f32, 0f32));
+        }
+
+        #[test]
+        fn length() {
+            assert_ulps_eq!(square().length(0.0001f32), 3f32);
+            assert_ulps_eq!(Spline::<CubicCurve<f32>>::new(vec![]).length(0.0001f32), 0f32);
+
+            let quadratic = QuadraticCurve::new(Vector2::new(0f32, 0f32), Vector2::new(1f32, 0f32), Vector2::new(0f32, 1f32));
+            let (left, right) = quadratic.subdivide(0.5f32);
+            let spline = Spline::new(vec![left, right]);
+            assert_abs_diff_eq!(spline.length(0.0001f32), quadratic.length(1f32), epsilon = 0.00001f32);
+        }
+
+        #[test]
+        fn parameterize() {
+            let spline = square();
+            assert_eq!(spline.parameterize(-1f32, 0.0001f32, 9), Some(0f32));
+            assert_eq!(spline.parameterize(0.5f32, 0.0001f32, 9), Some(0.5f32));
+            assert_eq!(spline.parameterize(1.5f32, 0.0001f32, 9), Some(1.5f32));
+            assert_eq!(spline.parameterize(2.5f32, 0.0001f32, 9), Some(3.5f32));
+            assert_eq!(spline.parameterize(4f32, 0.0001f32, 9), Some(4f32));
+
+            let spline = Spline::new(vec![LinearCurve::new(1f32, 0f32)]);
+            assert_eq!(spline.parameterize(0f32, 0.0001f32, 9), None);
+
+            let spline = Spline::new(vec![
+                CubicCurve::from_bezier(Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32), Vector2::new(2f32, -1f32), Vector2::new(3f32, 1f32)),
+                CubicCurve::from_bezier(Vector2::new(3f32, 1f32), Vector2::new(4f32, 3f32), Vector2::new(5f32, 0f32), Vector2::new(6f32, 0f32)),
+            ]);
+            let length = spline.length(0.0001f32);
+            for i in 0..=20 {
+                let v = length * i as f32 / 20f32;
+                let t = spline.parameterize(v, 0.0001f32, 16).unwrap();
+                let (index, local) = spline.locate(t).unwrap();
+                let mut before = 0f32;
+                for segment in spline.segments()[..index].iter() {
+                    before += segment.length(1f32, 0.0001f32);
+                }
+                assert_abs_diff_eq!(before + spline.segments()[index].length(local, 0.0001f32), v, epsilon = 0.001f32);
+            }
+        }
+
+        #[test]
+        fn nearest_point() {
+            let spline = square();
+            assert_ulps_eq!(spline.nearest_point(Vector2::new(0.5f32, -1f32)).unwrap(), 0.5f32);
+            assert_ulps_eq!(spline.nearest_point(Vector2::new(2f32, 0.25f32)).unwrap(), 1.25f32);
+            assert_ulps_eq!(spline.nearest_point(Vector2::new(0.25f32, 0.9f32)).unwrap(), 3.75f32);
+            assert_ulps_eq!(spline.nearest_point(Vector2::new(-1f32, -1f32)).unwrap(), 0f32);
+        }
+    }
+}
\ No newline at end of file
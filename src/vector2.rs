@@ -125,4 +125,71 @@ impl<T: Scalar> Vector2<T> {
             y,
         }
     }
+
+    pub fn length(self) -> T {
+        self.inner_product(self).sqrt()
+    }
+
+    pub fn normalize(self) -> Vector2<T> {
+        self / self.length()
+    }
+
+    // the z component of the cross product of the vectors extended into three dimensions
+    pub fn cross(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn component_mul(self, other: Self) -> Vector2<T> {
+        Vector2 {
+            x: self.x * other.x,
+            y: self.y * other.y,
+        }
+    }
+
+    pub fn component_div(self, other: Self) -> Vector2<T> {
+        Vector2 {
+            x: self.x / other.x,
+            y: self.y / other.y,
+        }
+    }
+
+    pub fn component_min(self, other: Self) -> Vector2<T> {
+        Vector2 {
+            x: if other.x < self.x { other.x } else { self.x },
+            y: if other.y < self.y { other.y } else { self.y },
+        }
+    }
+
+    pub fn component_max(self, other: Self) -> Vector2<T> {
+        Vector2 {
+            x: if other.x > self.x { other.x } else { self.x },
+            y: if other.y > self.y { other.y } else { self.y },
+        }
+    }
+
+    pub fn abs(self) -> Vector2<T> {
+        Vector2 {
+            x: self.x.abs(),
+            y: self.y.abs(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod vector2 {
+        use crate::Vector2;
+        use approx::assert_ulps_eq;
+
+        #[test]
+        fn cross() {
+            assert_ulps_eq!(Vector2::new(1f32, 0f32).cross(Vector2::new(0f32, 1f32)), 1f32);
+            assert_ulps_eq!(Vector2::new(0f32, 1f32).cross(Vector2::new(1f32, 0f32)), -1f32);
+        }
+
+        #[test]
+        fn length() {
+            assert_ulps_eq!(Vector2::new(3f32, 4f32).length(), 5f32);
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,247 @@
+use std::ops::{
+    Add,
+    AddAssign,
+    Div,
+    DivAssign,
+    Mul,
+    MulAssign,
+    Neg,
+    Sub,
+    SubAssign,
+};
+use crate::{
+    Scalar,
+    VectorSpace,
+    InnerProductSpace,
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Vector3<T: Scalar> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Scalar> Add for Vector3<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Vector3 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl<T: Scalar> AddAssign for Vector3<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+impl<T: Scalar> Div<T> for Vector3<T> {
+    type Output = Self;
+
+    fn div(self, other: T) -> Self::Output {
+        Vector3 {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+        }
+    }
+}
+
+impl<T: Scalar> DivAssign<T> for Vector3<T> {
+    fn div_assign(&mut self, other: T) {
+        self.x /= other;
+        self.y /= other;
+        self.z /= other;
+    }
+}
+
+impl<T: Scalar> Mul<T> for Vector3<T> {
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self::Output {
+        Vector3 {
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+        }
+    }
+}
+
+impl<T: Scalar> MulAssign<T> for Vector3<T> {
+    fn mul_assign(&mut self, other: T) {
+        self.x *= other;
+        self.y *= other;
+        self.z *= other;
+    }
+}
+
+impl<T: Scalar> Neg for Vector3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Vector3 {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl<T: Scalar> Sub for Vector3<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Vector3 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl<T: Scalar> SubAssign for Vector3<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+    }
+}
+
+impl<T: Scalar> VectorSpace for Vector3<T> {
+    type Scalar = T;
+
+    fn zero() -> Self {
+        Vector3::new(Scalar::from_i32(0), Scalar::from_i32(0), Scalar::from_i32(0))
+    }
+}
+
+impl<T: Scalar> InnerProductSpace for Vector3<T> {
+    fn inner_product(self, other: Self) -> Self::Scalar {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+}
+
+impl<T: Scalar> Vector3<T> {
+    pub fn new(x: T, y: T, z: T) -> Vector3<T> {
+        Vector3 {
+            x,
+            y,
+            z,
+        }
+    }
+
+    pub fn length(self) -> T {
+        self.inner_product(self).sqrt()
+    }
+
+    pub fn normalize(self) -> Vector3<T> {
+        self / self.length()
+    }
+
+    pub fn cross(self, other: Self) -> Vector3<T> {
+        Vector3 {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    pub fn component_mul(self, other: Self) -> Vector3<T> {
+        Vector3 {
+            x: self.x * other.x,
+            y: self.y * other.y,
+            z: self.z * other.z,
+        }
+    }
+
+    pub fn component_div(self, other: Self) -> Vector3<T> {
+        Vector3 {
+            x: self.x / other.x,
+            y: self.y / other.y,
+            z: self.z / other.z,
+        }
+    }
+
+    pub fn component_min(self, other: Self) -> Vector3<T> {
+        Vector3 {
+            x: if other.x < self.x { other.x } else { self.x },
+            y: if other.y < self.y { other.y } else { self.y },
+            z: if other.z < self.z { other.z } else { self.z },
+        }
+    }
+
+    pub fn component_max(self, other: Self) -> Vector3<T> {
+        Vector3 {
+            x: if other.x > self.x { other.x } else { self.x },
+            y: if other.y > self.y { other.y } else { self.y },
+            z: if other.z > self.z { other.z } else { self.z },
+        }
+    }
+
+    pub fn abs(self) -> Vector3<T> {
+        Vector3 {
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod vector3 {
+        use crate::{
+            CubicCurve,
+            InnerProductSpace,
+            Vector3,
+        };
+        use approx::assert_ulps_eq;
+
+        #[test]
+        fn cross() {
+            let x = Vector3::new(1f32, 0f32, 0f32);
+            let y = Vector3::new(0f32, 1f32, 0f32);
+            let z = Vector3::new(0f32, 0f32, 1f32);
+            assert_eq!(x.cross(y), z);
+            assert_eq!(y.cross(z), x);
+            assert_eq!(z.cross(x), y);
+            assert_eq!(y.cross(x), -z);
+
+            let a = Vector3::new(1f32, 2f32, 3f32);
+            let b = Vector3::new(-4f32, 5f32, 0.5f32);
+            assert_ulps_eq!(a.cross(b).inner_product(a), 0f32);
+            assert_ulps_eq!(a.cross(b).inner_product(b), 0f32);
+        }
+
+        #[test]
+        fn length() {
+            assert_ulps_eq!(Vector3::new(2f32, 3f32, 6f32).length(), 7f32);
+            assert_ulps_eq!(Vector3::new(2f32, 3f32, 6f32).normalize().length(), 1f32);
+        }
+
+        #[test]
+        fn component_wise() {
+            let a = Vector3::new(1f32, -2f32, 3f32);
+            let b = Vector3::new(2f32, 4f32, -1f32);
+            assert_eq!(a.component_mul(b), Vector3::new(2f32, -8f32, -3f32));
+            assert_eq!(a.component_div(b), Vector3::new(0.5f32, -0.5f32, -3f32));
+            assert_eq!(a.component_min(b), Vector3::new(1f32, -2f32, -1f32));
+            assert_eq!(a.component_max(b), Vector3::new(2f32, 4f32, 3f32));
+            assert_eq!(a.abs(), Vector3::new(1f32, 2f32, 3f32));
+        }
+
+        #[test]
+        fn curve() {
+            let curve = CubicCurve::from_bezier(Vector3::new(0f32, 0f32, 0f32), Vector3::new(1f32, 0f32, 0f32), Vector3::new(1f32, 1f32, 0f32), Vector3::new(1f32, 1f32, 1f32));
+            assert_eq!(curve.evaluate(1f32), Vector3::new(1f32, 1f32, 1f32));
+            assert_ulps_eq!(curve.nearest_point(Vector3::new(2f32, 2f32, 2f32)).0, 1f32);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,232 @@
+use std::ops::{
+    Add,
+    AddAssign,
+    Div,
+    DivAssign,
+    Mul,
+    MulAssign,
+    Neg,
+    Sub,
+    SubAssign,
+};
+use crate::{
+    Scalar,
+    VectorSpace,
+    InnerProductSpace,
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Vector4<T: Scalar> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+impl<T: Scalar> Add for Vector4<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Vector4 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+            w: self.w + other.w,
+        }
+    }
+}
+
+impl<T: Scalar> AddAssign for Vector4<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+        self.w += other.w;
+    }
+}
+
+impl<T: Scalar> Div<T> for Vector4<T> {
+    type Output = Self;
+
+    fn div(self, other: T) -> Self::Output {
+        Vector4 {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+            w: self.w / other,
+        }
+    }
+}
+
+impl<T: Scalar> DivAssign<T> for Vector4<T> {
+    fn div_assign(&mut self, other: T) {
+        self.x /= other;
+        self.y /= other;
+        self.z /= other;
+        self.w /= other;
+    }
+}
+
+impl<T: Scalar> Mul<T> for Vector4<T> {
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self::Output {
+        Vector4 {
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+            w: self.w * other,
+        }
+    }
+}
+
+impl<T: Scalar> MulAssign<T> for Vector4<T> {
+    fn mul_assign(&mut self, other: T) {
+        self.x *= other;
+        self.y *= other;
+        self.z *= other;
+        self.w *= other;
+    }
+}
+
+impl<T: Scalar> Neg for Vector4<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Vector4 {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: -self.w,
+        }
+    }
+}
+
+impl<T: Scalar> Sub for Vector4<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Vector4 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+            w: self.w - other.w,
+        }
+    }
+}
+
+impl<T: Scalar> SubAssign for Vector4<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+        self.w -= other.w;
+    }
+}
+
+impl<T: Scalar> VectorSpace for Vector4<T> {
+    type Scalar = T;
+
+    fn zero() -> Self {
+        Vector4::new(Scalar::from_i32(0), Scalar::from_i32(0), Scalar::from_i32(0), Scalar::from_i32(0))
+    }
+}
+
+impl<T: Scalar> InnerProductSpace for Vector4<T> {
+    fn inner_product(self, other: Self) -> Self::Scalar {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+}
+
+impl<T: Scalar> Vector4<T> {
+    pub fn new(x: T, y: T, z: T, w: T) -> Vector4<T> {
+        Vector4 {
+            x,
+            y,
+            z,
+            w,
+        }
+    }
+
+    pub fn length(self) -> T {
+        self.inner_product(self).sqrt()
+    }
+
+    pub fn normalize(self) -> Vector4<T> {
+        self / self.length()
+    }
+
+    pub fn component_mul(self, other: Self) -> Vector4<T> {
+        Vector4 {
+            x: self.x * other.x,
+            y: self.y * other.y,
+            z: self.z * other.z,
+            w: self.w * other.w,
+        }
+    }
+
+    pub fn component_div(self, other: Self) -> Vector4<T> {
+        Vector4 {
+            x: self.x / other.x,
+            y: self.y / other.y,
+            z: self.z / other.z,
+            w: self.w / other.w,
+        }
+    }
+
+    pub fn component_min(self, other: Self) -> Vector4<T> {
+        Vector4 {
+            x: if other.x < self.x { other.x } else { self.x },
+            y: if other.y < self.y { other.y } else { self.y },
+            z: if other.z < self.z { other.z } else { self.z },
+            w: if other.w < self.w { other.w } else { self.w },
+        }
+    }
+
+    pub fn component_max(self, other: Self) -> Vector4<T> {
+        Vector4 {
+            x: if other.x > self.x { other.x } else { self.x },
+            y: if other.y > self.y { other.y } else { self.y },
+            z: if other.z > self.z { other.z } else { self.z },
+            w: if other.w > self.w { other.w } else { self.w },
+        }
+    }
+
+    pub fn abs(self) -> Vector4<T> {
+        Vector4 {
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs(),
+            w: self.w.abs(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod vector4 {
+        use crate::{
+            CubicCurve,
+            Vector4,
+        };
+        use approx::assert_ulps_eq;
+
+        #[test]
+        fn length() {
+            assert_ulps_eq!(Vector4::new(1f32, 1f32, 1f32, 1f32).length(), 2f32);
+            assert_eq!(Vector4::new(0f32, 0f32, -2f32, 0f32).normalize(), Vector4::new(0f32, 0f32, -1f32, 0f32));
+        }
+
+        #[test]
+        fn component_wise() {
+            let a = Vector4::new(0.25f32, 0.5f32, 0.75f32, 1f32);
+            assert_eq!(a.component_mul(Vector4::new(4f32, 2f32, 0f32, 1f32)), Vector4::new(1f32, 1f32, 0f32, 1f32));
+        }
+
+        #[test]
+        fn curve() {
+            let curve = CubicCurve::from_bezier(Vector4::new(1f32, 0f32, 0f32, 1f32), Vector4::new(1f32, 0f32, 0f32, 1f32), Vector4::new(0f32, 0f32, 1f32, 1f32), Vector4::new(0f32, 0f32, 1f32, 1f32));
+            assert_eq!(curve.evaluate(0.5f32), Vector4::new(0.5f32, 0f32, 0.5f32, 1f32));
+        }
+    }
+}
\ No newline at end of file